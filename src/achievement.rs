@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+
+use crate::event::GameEvent;
+use crate::factory::plant_factory::PlantType;
+use crate::stats::MatchStats;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Condition {
+    /// A single blast from `plant` finishes off at least `count` zombies
+    BlastKills { plant: PlantType, count: u32 },
+    /// Win a match without ever placing `plant`
+    WinWithout(PlantType),
+    /// Get past `waves` waves having placed nothing but `plants`
+    SurviveWavesWithOnly {
+        waves: u32,
+        plants: &'static [PlantType],
+    },
+    /// Kill this many zombies in one match
+    MatchKills(u32),
+    /// Collect this much sun in one match
+    SunCollected(i32),
+}
+
+pub struct Achievement {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub condition: Condition,
+}
+
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "cherry_picker",
+        name: "Cherry Picker",
+        description: "Kill 10 zombies with a single Cherry Bomb.",
+        condition: Condition::BlastKills {
+            plant: PlantType::CherryBomb,
+            count: 10,
+        },
+    },
+    Achievement {
+        id: "no_fun_allowed",
+        name: "No Fun Allowed",
+        description: "Win a match without planting any Sunflowers.",
+        condition: Condition::WinWithout(PlantType::Sunflower),
+    },
+    Achievement {
+        id: "great_wall",
+        name: "Great Wall",
+        description: "Survive 20 waves using only Wall-nuts and Potato Mines.",
+        condition: Condition::SurviveWavesWithOnly {
+            waves: 20,
+            plants: &[PlantType::Wallnut, PlantType::PotatoMine],
+        },
+    },
+    Achievement {
+        id: "zombie_slayer",
+        name: "Zombie Slayer",
+        description: "Kill 100 zombies in one match.",
+        condition: Condition::MatchKills(100),
+    },
+    Achievement {
+        id: "sunny_day",
+        name: "Sunny Day",
+        description: "Collect 2000 sun in one match.",
+        condition: Condition::SunCollected(2000),
+    },
+];
+
+/// Checks achievement conditions during a match
+pub struct AchievementTracker {
+    pub unlocked: HashSet<String>,
+    /// Unlocked during this match, in order, for toasts and saving
+    pub newly_unlocked: Vec<&'static Achievement>,
+    announced: usize,
+}
+
+impl AchievementTracker {
+    pub fn new(unlocked: HashSet<String>) -> Self {
+        Self {
+            unlocked,
+            newly_unlocked: Vec::new(),
+            announced: 0,
+        }
+    }
+
+    pub fn check(&mut self, event: &GameEvent, stats: &MatchStats) {
+        for achievement in ACHIEVEMENTS {
+            let met = match achievement.condition {
+                Condition::BlastKills { plant, count } => matches!(
+                    *event,
+                    GameEvent::BlastFinished { source, kills } if source == plant && kills >= count
+                ),
+                Condition::SurviveWavesWithOnly { waves, plants } => {
+                    matches!(event, GameEvent::WaveStarted { .. })
+                        && stats.waves > waves
+                        && !stats.plants_placed.is_empty()
+                        && stats.plants_placed.keys().all(|p| plants.contains(p))
+                }
+                Condition::MatchKills(count) => stats.total_kills() >= count,
+                Condition::SunCollected(amount) => stats.sun_collected >= amount,
+                Condition::WinWithout(_) => false,
+            };
+            if met {
+                self.unlock(achievement);
+            }
+        }
+    }
+
+    /// Evaluates the conditions that can only be judged once the match is over
+    pub fn finish_match(&mut self, stats: &MatchStats, won: bool) {
+        for achievement in ACHIEVEMENTS {
+            if let Condition::WinWithout(plant) = achievement.condition
+                && won
+                && !stats.plants_placed.contains_key(&plant)
+            {
+                self.unlock(achievement);
+            }
+        }
+    }
+
+    /// Unlocks that have not been shown to the player yet
+    pub fn take_announcements(&mut self) -> &[&'static Achievement] {
+        let start = self.announced;
+        self.announced = self.newly_unlocked.len();
+        &self.newly_unlocked[start..]
+    }
+
+    fn unlock(&mut self, achievement: &'static Achievement) {
+        if self.unlocked.insert(achievement.id.to_string()) {
+            self.newly_unlocked.push(achievement);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::achievement::ACHIEVEMENTS;
+use crate::profile::Profile;
+use macroquad::prelude::*;
+
+pub struct AchievementsScreen {
+    pub back: bool,
+}
+
+impl AchievementsScreen {
+    pub fn new() -> Self {
+        Self { back: false }
+    }
+
+    fn back_button() -> Rect {
+        Rect::new(
+            screen_width() / 2.0 - 80.0,
+            screen_height() - 100.0,
+            160.0,
+            50.0,
+        )
+    }
+
+    pub fn update(&mut self) {
+        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Enter) {
+            self.back = true;
+        }
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let mouse: Vec2 = mouse_position().into();
+            if Self::back_button().contains(mouse) {
+                self.back = true;
+            }
+        }
+    }
+
+    pub fn draw(&self, profile: &Profile) {
+        clear_background(LIGHTGRAY);
+
+        let unlocked = ACHIEVEMENTS
+            .iter()
+            .filter(|a| profile.achievements.contains(a.id))
+            .count();
+        draw_text("Achievements", 80.0, 80.0, 40.0, DARKGREEN);
+        draw_text(
+            &format!("{} / {} unlocked", unlocked, ACHIEVEMENTS.len()),
+            80.0,
+            110.0,
+            24.0,
+            BLACK,
+        );
+
+        let row_h = 70.0;
+        for (i, achievement) in ACHIEVEMENTS.iter().enumerate() {
+            let x = 80.0;
+            let y = 140.0 + i as f32 * row_h;
+            let w = screen_width() - 160.0;
+            let done = profile.achievements.contains(achievement.id);
+
+            draw_rectangle(x, y, w, row_h - 10.0, if done { WHITE } else { GRAY });
+            draw_rectangle_lines(
+                x,
+                y,
+                w,
+                row_h - 10.0,
+                2.0,
+                if done { GOLD } else { DARKGRAY },
+            );
+
+            // trophy
+            let trophy = if done { GOLD } else { DARKGRAY };
+            draw_circle(x + 30.0, y + 30.0, 16.0, trophy);
+
+            draw_text(achievement.name, x + 60.0, y + 26.0, 26.0, BLACK);
+            draw_text(achievement.description, x + 60.0, y + 48.0, 18.0, DARKGRAY);
+        }
+
+        // Button
+        let btn = Self::back_button();
+        draw_rectangle(btn.x, btn.y, btn.w, btn.h, WHITE);
+        draw_rectangle_lines(btn.x, btn.y, btn.w, btn.h, 3.0, BLACK);
+
+        let btn_text = "Back";
+        let metrics = measure_text(btn_text, None, 30, 1.0);
+        draw_text(
+            btn_text,
+            btn.x + (btn.w - metrics.width) / 2.0,
+            btn.y + btn.h / 2.0 + metrics.height / 2.0,
+            30.0,
+            BLACK,
+        );
+    }
+}
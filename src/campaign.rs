@@ -0,0 +1,879 @@
+use crate::factory::{plant_factory::PlantType, zombie_factory::ZombieType};
+use strum::IntoEnumIterator;
+
+use ZombieType::*;
+
+pub struct ScriptedWave {
+    /// Seconds after the previous wave
+    pub delay: f32,
+    pub zombies: &'static [ZombieType],
+    pub flag: bool,
+}
+
+const fn wave(delay: f32, zombies: &'static [ZombieType]) -> ScriptedWave {
+    ScriptedWave {
+        delay,
+        zombies,
+        flag: false,
+    }
+}
+
+const fn flag(delay: f32, zombies: &'static [ZombieType]) -> ScriptedWave {
+    ScriptedWave {
+        delay,
+        zombies,
+        flag: true,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeOfDay {
+    Day,
+    /// No sun falls from the sky, and mushrooms are awake
+    Night,
+}
+
+pub struct Level {
+    pub name: &'static str,
+    pub waves: &'static [ScriptedWave],
+    pub seed_slots: usize,
+    pub starting_sun: i32,
+    pub time_of_day: TimeOfDay,
+    /// Plant unlocked the first time this level is completed
+    pub reward: Option<PlantType>,
+}
+
+pub const STARTER_PLANTS: &[PlantType] = &[PlantType::Peashooter];
+
+pub const LEVELS: &[Level] = &[
+    Level {
+        name: "Front Lawn",
+        waves: &[
+            wave(25.0, &[Basic]),
+            wave(20.0, &[Basic]),
+            wave(20.0, &[Basic, Basic]),
+            flag(20.0, &[Basic, Basic, Basic]),
+        ],
+        seed_slots: 1,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Sunflower),
+    },
+    Level {
+        name: "Sunny Street",
+        waves: &[
+            wave(25.0, &[Basic]),
+            wave(20.0, &[Basic, Basic]),
+            wave(20.0, &[Basic, Conehead]),
+            wave(20.0, &[Basic, Basic, Basic]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead]),
+        ],
+        seed_slots: 2,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::CherryBomb),
+    },
+    Level {
+        name: "Cone Zone",
+        waves: &[
+            wave(25.0, &[Conehead]),
+            wave(20.0, &[Basic, Conehead]),
+            wave(18.0, &[Basic, Basic, Conehead]),
+            flag(20.0, &[Basic, Basic, Basic, Conehead, Conehead]),
+            wave(20.0, &[Conehead, Conehead, Basic]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, Conehead, Conehead],
+            ),
+        ],
+        seed_slots: 3,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Wallnut),
+    },
+    Level {
+        name: "Vaulting Season",
+        waves: &[
+            wave(25.0, &[Basic, PoleZombie]),
+            wave(20.0, &[Conehead, PoleZombie]),
+            wave(18.0, &[Basic, Basic, PoleZombie]),
+            flag(20.0, &[Basic, Conehead, PoleZombie, PoleZombie, PoleZombie]),
+            wave(20.0, &[Conehead, Conehead, PoleZombie]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, PoleZombie, PoleZombie],
+            ),
+        ],
+        seed_slots: 4,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::PotatoMine),
+    },
+    Level {
+        name: "Bucket Brigade",
+        waves: &[
+            wave(25.0, &[Basic, Conehead]),
+            wave(20.0, &[Buckethead]),
+            wave(18.0, &[Basic, Conehead, PoleZombie]),
+            flag(20.0, &[Basic, Basic, Conehead, Buckethead, Buckethead]),
+            wave(20.0, &[Conehead, Buckethead, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Buckethead, PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 5,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::SlowPeashooter),
+    },
+    Level {
+        name: "Double Trouble",
+        waves: &[
+            wave(25.0, &[Basic, Basic]),
+            wave(20.0, &[Basic, Conehead]),
+            wave(18.0, &[Conehead, Conehead]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead]),
+            wave(20.0, &[Basic, Conehead, Buckethead]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead, Buckethead]),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Repeater),
+    },
+    Level {
+        name: "Three Lanes Wide",
+        waves: &[
+            wave(25.0, &[Basic, Conehead]),
+            wave(20.0, &[Basic, Basic, Conehead]),
+            wave(18.0, &[Conehead, PoleZombie]),
+            flag(20.0, &[Basic, Basic, Conehead, PoleZombie]),
+            wave(20.0, &[Conehead, Conehead, Buckethead]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, PoleZombie, Buckethead],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Threepeater),
+    },
+    Level {
+        name: "Flanked",
+        waves: &[
+            wave(25.0, &[Basic, Basic]),
+            wave(20.0, &[Conehead, PoleZombie]),
+            wave(18.0, &[Basic, Conehead, PoleZombie]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead, PoleZombie]),
+            wave(18.0, &[PoleZombie, PoleZombie, Buckethead]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, PoleZombie, PoleZombie, Buckethead,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::SplitPea),
+    },
+    Level {
+        name: "Hungry Garden",
+        waves: &[
+            wave(25.0, &[Conehead]),
+            wave(20.0, &[Basic, Conehead, Conehead]),
+            wave(18.0, &[Buckethead]),
+            flag(20.0, &[Basic, Conehead, Conehead, Buckethead]),
+            wave(20.0, &[Conehead, Conehead, PoleZombie, Buckethead]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Buckethead, Buckethead, PoleZombie],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Chomper),
+    },
+    Level {
+        name: "Squash Court",
+        waves: &[
+            wave(25.0, &[Basic, Conehead]),
+            wave(20.0, &[Conehead, PoleZombie]),
+            wave(18.0, &[Buckethead, Basic]),
+            flag(20.0, &[Basic, Conehead, PoleZombie, PoleZombie, Buckethead]),
+            wave(18.0, &[Conehead, Conehead, Buckethead]),
+            wave(18.0, &[Basic, PoleZombie, Buckethead]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Squash),
+    },
+    Level {
+        name: "Hot Lane",
+        waves: &[
+            wave(25.0, &[Basic, Basic, Basic]),
+            wave(20.0, &[Conehead, Conehead]),
+            wave(18.0, &[Basic, Basic, Conehead, Conehead]),
+            flag(20.0, &[Basic, Basic, Basic, Conehead, Conehead, Buckethead]),
+            wave(18.0, &[Conehead, Conehead, Buckethead, Buckethead]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Basic, Conehead, Conehead, Conehead, Buckethead, Buckethead,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Jalapeno),
+    },
+    Level {
+        name: "Screen Test",
+        waves: &[
+            wave(25.0, &[Basic, ScreenDoor]),
+            wave(20.0, &[Conehead, ScreenDoor]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor]),
+            flag(20.0, &[Basic, Basic, Conehead, ScreenDoor, ScreenDoor]),
+            wave(20.0, &[Conehead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, ScreenDoor, ScreenDoor,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Spikeweed),
+    },
+    Level {
+        name: "High Fences",
+        waves: &[
+            wave(25.0, &[PoleZombie]),
+            wave(20.0, &[Basic, PoleZombie, PoleZombie]),
+            wave(18.0, &[Conehead, PoleZombie, ScreenDoor]),
+            flag(20.0, &[Basic, Conehead, PoleZombie, PoleZombie, PoleZombie]),
+            wave(18.0, &[Buckethead, PoleZombie, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, PoleZombie, PoleZombie, PoleZombie, Buckethead,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::TallNut),
+    },
+    Level {
+        name: "Iron Guard",
+        waves: &[
+            wave(25.0, &[Conehead, Buckethead]),
+            wave(20.0, &[ScreenDoor, Buckethead]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor, Buckethead]),
+            flag(20.0, &[Basic, Conehead, Buckethead, Buckethead, ScreenDoor]),
+            wave(18.0, &[Conehead, Conehead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Buckethead, Buckethead, Buckethead, ScreenDoor, ScreenDoor,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Pumpkin),
+    },
+    Level {
+        name: "Fire Walk",
+        waves: &[
+            wave(25.0, &[Basic, Conehead, ScreenDoor]),
+            wave(20.0, &[Conehead, Conehead, Buckethead]),
+            wave(18.0, &[Basic, ScreenDoor, ScreenDoor, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, ScreenDoor, PoleZombie,
+                ],
+            ),
+            wave(18.0, &[Conehead, Buckethead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                    PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Torchwood),
+    },
+    Level {
+        name: "Rapid Fire",
+        waves: &[
+            wave(25.0, &[Basic, Conehead, Conehead]),
+            wave(18.0, &[Conehead, Buckethead, ScreenDoor]),
+            wave(18.0, &[Basic, Basic, PoleZombie, PoleZombie, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                ],
+            ),
+            wave(
+                18.0,
+                &[Conehead, Buckethead, Buckethead, ScreenDoor, ScreenDoor],
+            ),
+            wave(18.0, &[Basic, Conehead, PoleZombie, Buckethead, Buckethead]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead,
+                    ScreenDoor, ScreenDoor,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::GatlingPea),
+    },
+    Level {
+        name: "Moonlit Lawn",
+        waves: &[
+            wave(25.0, &[Basic, Basic]),
+            wave(20.0, &[Conehead, Basic]),
+            wave(18.0, &[Basic, Conehead, PoleZombie]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead, PoleZombie]),
+            wave(20.0, &[Buckethead, Conehead, Basic]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, Buckethead, PoleZombie],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::PuffShroom),
+    },
+    Level {
+        name: "Midnight Snack",
+        waves: &[
+            wave(25.0, &[Basic, Basic]),
+            wave(20.0, &[Basic, Conehead]),
+            wave(18.0, &[Conehead, Conehead, PoleZombie]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead, PoleZombie]),
+            wave(20.0, &[Conehead, Buckethead, Basic]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, Buckethead, PoleZombie],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::SunShroom),
+    },
+    Level {
+        name: "Fog Bank",
+        waves: &[
+            wave(25.0, &[Basic, ScreenDoor]),
+            wave(20.0, &[Conehead, ScreenDoor]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor, PoleZombie]),
+            flag(20.0, &[Basic, Conehead, ScreenDoor, ScreenDoor, PoleZombie]),
+            wave(20.0, &[Conehead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, ScreenDoor, ScreenDoor,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::FumeShroom),
+    },
+    Level {
+        name: "Nervous Night",
+        waves: &[
+            wave(25.0, &[Basic, Conehead]),
+            wave(20.0, &[Conehead, Conehead, PoleZombie]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor, Buckethead]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, ScreenDoor, PoleZombie],
+            ),
+            wave(18.0, &[Conehead, Buckethead, Buckethead]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, ScreenDoor, ScreenDoor, PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::ScaredyShroom),
+    },
+    Level {
+        name: "Cold Snap",
+        waves: &[
+            wave(25.0, &[Basic, Basic, Conehead]),
+            wave(20.0, &[Conehead, Conehead, Buckethead]),
+            wave(18.0, &[Basic, Basic, Conehead, Conehead, PoleZombie]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, Buckethead, Buckethead],
+            ),
+            wave(18.0, &[Conehead, Buckethead, ScreenDoor, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                    PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::IceShroom),
+    },
+    Level {
+        name: "Graveyard Shift",
+        waves: &[
+            wave(25.0, &[Conehead, Conehead]),
+            wave(20.0, &[Buckethead, ScreenDoor]),
+            wave(18.0, &[Basic, Conehead, Buckethead, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                ],
+            ),
+            wave(
+                18.0,
+                &[Conehead, Buckethead, Buckethead, ScreenDoor, PoleZombie],
+            ),
+            wave(18.0, &[Basic, Basic, Conehead, Buckethead, Buckethead]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead,
+                    ScreenDoor, PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::DoomShroom),
+    },
+    Level {
+        name: "Metal Detector",
+        waves: &[
+            wave(25.0, &[Buckethead]),
+            wave(20.0, &[Conehead, ScreenDoor]),
+            wave(18.0, &[Buckethead, Buckethead, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Buckethead, Buckethead, ScreenDoor, ScreenDoor,
+                ],
+            ),
+            wave(18.0, &[Conehead, Buckethead, ScreenDoor, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead, ScreenDoor,
+                    ScreenDoor,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::MagnetShroom),
+    },
+    Level {
+        name: "Gloom and Doom",
+        waves: &[
+            wave(25.0, &[Basic, Conehead, ScreenDoor]),
+            wave(20.0, &[Conehead, Buckethead, Buckethead]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor, ScreenDoor, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                ],
+            ),
+            wave(
+                18.0,
+                &[Conehead, Buckethead, Buckethead, ScreenDoor, ScreenDoor],
+            ),
+            wave(
+                18.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, PoleZombie, PoleZombie,
+                ],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead,
+                    ScreenDoor, ScreenDoor, PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::GloomShroom),
+    },
+    Level {
+        name: "Last Light",
+        waves: &[
+            wave(25.0, &[Basic, Conehead, Conehead]),
+            wave(20.0, &[Conehead, Buckethead, ScreenDoor]),
+            wave(18.0, &[Basic, Conehead, Buckethead, PoleZombie, PoleZombie]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                ],
+            ),
+            wave(
+                18.0,
+                &[
+                    Conehead, Conehead, Buckethead, Buckethead, ScreenDoor, ScreenDoor,
+                ],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Conehead, Buckethead, Buckethead, Buckethead,
+                    ScreenDoor, ScreenDoor, PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 150,
+        time_of_day: TimeOfDay::Night,
+        reward: Some(PlantType::CoffeeBean),
+    },
+    Level {
+        name: "Lane Shuffle",
+        waves: &[
+            wave(25.0, &[Basic, Basic, Conehead]),
+            wave(20.0, &[Conehead, Conehead, PoleZombie]),
+            wave(18.0, &[Basic, Conehead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, Buckethead, PoleZombie],
+            ),
+            wave(18.0, &[Conehead, Buckethead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                    PoleZombie,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Garlic),
+    },
+    Level {
+        name: "Star Crossed",
+        waves: &[
+            wave(25.0, &[Basic, Conehead]),
+            wave(20.0, &[Conehead, Conehead, ScreenDoor]),
+            wave(18.0, &[Basic, Conehead, Buckethead, PoleZombie]),
+            flag(
+                20.0,
+                &[Basic, Basic, Conehead, Conehead, Buckethead, ScreenDoor],
+            ),
+            wave(
+                18.0,
+                &[Conehead, Buckethead, Buckethead, PoleZombie, PoleZombie],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor,
+                    ScreenDoor,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Starfruit),
+    },
+    Level {
+        name: "Incoming!",
+        waves: &[
+            wave(25.0, &[Basic, Catapult]),
+            wave(20.0, &[Conehead, Conehead]),
+            wave(18.0, &[Basic, Conehead, Catapult]),
+            flag(20.0, &[Basic, Basic, Conehead, Conehead, Catapult]),
+            wave(18.0, &[Conehead, Buckethead, Catapult]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Buckethead, Catapult, Catapult,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::CabbagePult),
+    },
+    Level {
+        name: "Popcorn",
+        waves: &[
+            wave(25.0, &[Basic, Conehead, Catapult]),
+            wave(20.0, &[Conehead, Buckethead]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor, Catapult]),
+            flag(
+                20.0,
+                &[Basic, Conehead, Conehead, Buckethead, Catapult, Catapult],
+            ),
+            wave(18.0, &[Conehead, Buckethead, Buckethead, ScreenDoor]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Catapult, Catapult,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::KernelPult),
+    },
+    Level {
+        name: "Sunrise",
+        waves: &[
+            wave(25.0, &[Basic, Conehead]),
+            wave(20.0, &[Conehead, Buckethead, ScreenDoor]),
+            wave(18.0, &[Basic, Conehead, Buckethead, Catapult]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, ScreenDoor, Catapult,
+                ],
+            ),
+            wave(
+                18.0,
+                &[Conehead, Buckethead, Buckethead, ScreenDoor, PoleZombie],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor, Catapult,
+                    Catapult,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::TwinSunflower),
+    },
+    Level {
+        name: "Heavy Artillery",
+        waves: &[
+            wave(25.0, &[Buckethead, Catapult]),
+            wave(20.0, &[Conehead, Conehead, Catapult]),
+            wave(18.0, &[Basic, Conehead, Buckethead, ScreenDoor, Catapult]),
+            flag(
+                20.0,
+                &[Basic, Conehead, Buckethead, Buckethead, Catapult, Catapult],
+            ),
+            wave(
+                18.0,
+                &[Conehead, Buckethead, Buckethead, ScreenDoor, Catapult],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead, Catapult,
+                    Catapult, Catapult,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::MelonPult),
+    },
+    Level {
+        name: "Up, Up and Away",
+        waves: &[
+            wave(25.0, &[Basic, Balloon]),
+            wave(20.0, &[Conehead, Balloon]),
+            wave(18.0, &[Basic, Conehead, Balloon, PoleZombie]),
+            flag(20.0, &[Basic, Basic, Conehead, Balloon, Balloon]),
+            wave(18.0, &[Conehead, Buckethead, Balloon]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Balloon, Balloon, Balloon,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Cactus),
+    },
+    Level {
+        name: "Gusty Day",
+        waves: &[
+            wave(25.0, &[Basic, Balloon, Balloon]),
+            wave(20.0, &[Conehead, Buckethead, Balloon]),
+            wave(18.0, &[Basic, Conehead, ScreenDoor, Balloon, Balloon]),
+            flag(
+                20.0,
+                &[
+                    Basic, Conehead, Conehead, Buckethead, Balloon, Balloon, Balloon,
+                ],
+            ),
+            wave(18.0, &[Conehead, Buckethead, Buckethead, Balloon]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Balloon, Balloon,
+                    Balloon,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::Blover),
+    },
+    Level {
+        name: "Bungee Blitz",
+        waves: &[
+            wave(25.0, &[Basic, Bungee]),
+            wave(20.0, &[Conehead, Catapult]),
+            wave(18.0, &[Basic, Conehead, Bungee]),
+            flag(20.0, &[Basic, Conehead, Conehead, Bungee, Bungee, Catapult]),
+            wave(18.0, &[Conehead, Buckethead, Bungee, Catapult]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Bungee, Bungee,
+                    Catapult,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::UmbrellaLeaf),
+    },
+    Level {
+        name: "Deep Freeze",
+        waves: &[
+            wave(25.0, &[Conehead, Buckethead]),
+            wave(20.0, &[Conehead, Buckethead, ScreenDoor, Balloon]),
+            wave(18.0, &[Basic, Conehead, Buckethead, Catapult, Bungee]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, ScreenDoor, Balloon,
+                ],
+            ),
+            wave(18.0, &[Conehead, Buckethead, Buckethead, Catapult, Bungee]),
+            wave(
+                18.0,
+                &[Basic, Conehead, PoleZombie, PoleZombie, Buckethead, Balloon],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead,
+                    ScreenDoor, Catapult, Bungee, Balloon,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: Some(PlantType::WinterMelon),
+    },
+    Level {
+        name: "Final Stand",
+        waves: &[
+            wave(25.0, &[Basic, Conehead, ScreenDoor]),
+            wave(18.0, &[Conehead, PoleZombie, Balloon, Catapult]),
+            wave(18.0, &[Buckethead, ScreenDoor, Bungee]),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, PoleZombie, ScreenDoor, Balloon,
+                ],
+            ),
+            wave(18.0, &[Buckethead, Buckethead, Catapult, Bungee]),
+            wave(
+                18.0,
+                &[Conehead, Conehead, ScreenDoor, ScreenDoor, Balloon, Balloon],
+            ),
+            flag(
+                20.0,
+                &[
+                    Basic, Basic, Conehead, Conehead, Buckethead, Buckethead, Buckethead,
+                    PoleZombie, ScreenDoor, ScreenDoor, Catapult, Bungee, Balloon,
+                ],
+            ),
+        ],
+        seed_slots: 6,
+        starting_sun: 50,
+        time_of_day: TimeOfDay::Day,
+        reward: None,
+    },
+];
+
+/// A plant is unlocked if it is a starter or the reward of a completed level
+pub fn is_unlocked(plant: PlantType, levels_completed: usize) -> bool {
+    if STARTER_PLANTS.contains(&plant) {
+        return true;
+    }
+    match LEVELS.iter().position(|l| l.reward == Some(plant)) {
+        Some(level) => level < levels_completed,
+        None => false,
+    }
+}
+
+pub fn unlocked_plants(levels_completed: usize) -> Vec<PlantType> {
+    PlantType::iter()
+        .filter(|p| is_unlocked(*p, levels_completed))
+        .collect()
+}
@@ -6,7 +6,9 @@ pub const COLS: usize = 9;
 
 pub const TILE_SIZE: f32 = 100.0;
 
+#[allow(dead_code)]
 pub const TILE_WIDTH: f32 = TILE_SIZE;
+#[allow(dead_code)]
 pub const TILE_HEIGHT: f32 = TILE_SIZE;
 
 // Plant Bar
@@ -17,6 +19,9 @@ pub const SLOT_PADDING: f32 = 10.0;
 pub const NATURAL_SUN_MIN_INTERVAL: f32 = 7.0;
 pub const NATURAL_SUN_MAX_INTERVAL: f32 = 10.0;
 
+// how fast a pea fired into a neighbouring lane slides over to it
+pub const LANE_DRIFT_SPEED: f32 = 400.0;
+
 pub const SUN_VALUE: i32 = 25;
 
 pub const NATURAL_ZOMBIE_MIN_SPAWN_INTERVAL: f32 = 5.0;
@@ -24,3 +29,6 @@ pub const NATURAL_ZOMBIE_MAX_SPAWN_INTERVAL: f32 = 10.0;
 pub const MAX_ZOMBIE_SPAWN: i32 = 4;
 
 pub const MAX_SELECTED_PLANTS: usize = 6;
+
+pub const PROFILE_PATH: &str = "profile.txt";
+pub const MAX_SURVIVAL_SCORES: usize = 5;
@@ -0,0 +1,81 @@
+use crate::factory::{plant_factory::PlantType, zombie_factory::ZombieType};
+use crate::sun::SunSource;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    ZombieSpawned {
+        zombie_type: ZombieType,
+        row: usize,
+    },
+    ZombieDied {
+        zombie_type: ZombieType,
+        x: f32,
+        y: f32,
+    },
+    PlantPlaced {
+        plant_type: PlantType,
+        row: usize,
+        col: usize,
+    },
+    PlantEaten {
+        plant_type: PlantType,
+        x: f32,
+        y: f32,
+    },
+    SunProduced {
+        value: i32,
+        source: SunSource,
+    },
+    SunCollected {
+        value: i32,
+        source: SunSource,
+    },
+    ProjectileHit {
+        source: PlantType,
+        target: ZombieType,
+        damage: i32,
+        x: f32,
+        y: f32,
+    },
+    LaneBreached {
+        row: usize,
+    },
+    /// An area attack finished; `kills` counts zombies it finished off
+    BlastFinished {
+        source: PlantType,
+        kills: u32,
+    },
+    WaveStarted {
+        wave: u32,
+        /// Flag waves are the big ones that close out a section of a round
+        flag: bool,
+    },
+    RoundCleared {
+        round: u32,
+    },
+}
+
+/// Anything that wants to react to gameplay events (stats, audio, particles...)
+pub trait EventListener {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// Events raised during a tick; `Game::update` drains it once at the end
+#[derive(Default)]
+pub struct EventQueue {
+    events: Vec<GameEvent>,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    pub fn push(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
@@ -1,6 +1,41 @@
 use crate::plant::{
-    cherry_bomb::CherryBomb, peashooter::Peashooter, plant::Plant, potato_mine::PotatoMine,
-    slow_peashooter::SlowPeashooter, sunflower::Sunflower, wallnut::Wallnut,
+    blover::Blover,
+    cabbage_pult::CabbagePult,
+    cactus::Cactus,
+    cherry_bomb::CherryBomb,
+    chomper::Chomper,
+    coffee_bean::CoffeeBean,
+    doom_shroom::DoomShroom,
+    fume_shroom::FumeShroom,
+    garlic::Garlic,
+    gatling_pea::GatlingPea,
+    gloom_shroom::GloomShroom,
+    ice_shroom::IceShroom,
+    jalapeno::Jalapeno,
+    kernel_pult::KernelPult,
+    magnet_shroom::MagnetShroom,
+    melon_pult::MelonPult,
+    peashooter::Peashooter,
+    plant::{Plant, PlantEffect, PlantLayer},
+    potato_mine::PotatoMine,
+    puff_shroom::PuffShroom,
+    pumpkin::Pumpkin,
+    repeater::Repeater,
+    scaredy_shroom::ScaredyShroom,
+    slow_peashooter::SlowPeashooter,
+    spikeweed::Spikeweed,
+    split_pea::SplitPea,
+    squash::Squash,
+    starfruit::Starfruit,
+    sun_shroom::SunShroom,
+    sunflower::Sunflower,
+    tall_nut::TallNut,
+    threepeater::Threepeater,
+    torchwood::Torchwood,
+    twin_sunflower::TwinSunflower,
+    umbrella_leaf::UmbrellaLeaf,
+    wallnut::Wallnut,
+    winter_melon::WinterMelon,
 };
 use macroquad::prelude::*;
 use strum_macros::EnumIter;
@@ -13,6 +48,36 @@ pub enum PlantType {
     PotatoMine,
     Wallnut,
     CherryBomb,
+    Repeater,
+    GatlingPea,
+    Threepeater,
+    SplitPea,
+    Chomper,
+    Squash,
+    Jalapeno,
+    TallNut,
+    Pumpkin,
+    Spikeweed,
+    Torchwood,
+    CabbagePult,
+    KernelPult,
+    MelonPult,
+    PuffShroom,
+    SunShroom,
+    FumeShroom,
+    ScaredyShroom,
+    IceShroom,
+    DoomShroom,
+    MagnetShroom,
+    Garlic,
+    Starfruit,
+    TwinSunflower,
+    WinterMelon,
+    GloomShroom,
+    CoffeeBean,
+    Cactus,
+    Blover,
+    UmbrellaLeaf,
 }
 impl PlantType {
     pub fn description(&self) -> &'static str {
@@ -23,6 +88,54 @@ impl PlantType {
             PlantType::PotatoMine => "Explodes when a zombie steps on it when fully grown.",
             PlantType::Wallnut => "A sturdy wall that blocks zombies.",
             PlantType::CherryBomb => "A cherry that explodes and damages all zombies in an area.",
+            PlantType::Repeater => "Shoots two peas at a time.",
+            PlantType::GatlingPea => "Shoots four peas at a time. Must be planted on a Repeater.",
+            PlantType::Threepeater => "Shoots peas in three lanes.",
+            PlantType::SplitPea => "Shoots forward and two peas backward.",
+            PlantType::Chomper => "Swallows a zombie whole, then chews for a long time.",
+            PlantType::Squash => "Squashes the closest zombie within one tile.",
+            PlantType::Jalapeno => "Burns every zombie in its lane.",
+            PlantType::TallNut => "A tough wall that zombies can't vault over.",
+            PlantType::Pumpkin => "Shields the plant inside it. Can be planted over other plants.",
+            PlantType::Spikeweed => "Hurts zombies that walk over it. Pops wheeled zombies.",
+            PlantType::Torchwood => "Peas that pass through it become fire peas.",
+            PlantType::CabbagePult => "Lobs cabbages at zombies.",
+            PlantType::KernelPult => "Lobs kernels and butter that stuns zombies.",
+            PlantType::MelonPult => "Lobs heavy melons that splash nearby zombies.",
+            PlantType::PuffShroom => "Free, short-range spore shooter. Sleeps during the day.",
+            PlantType::SunShroom => "Makes small sun that grows bigger. Sleeps during the day.",
+            PlantType::FumeShroom => {
+                "Fumes hit every zombie within four tiles. Sleeps during the day."
+            }
+            PlantType::ScaredyShroom => {
+                "Long-range shooter that hides when zombies get close. Sleeps during the day."
+            }
+            PlantType::IceShroom => "Freezes every zombie on the lawn. Sleeps during the day.",
+            PlantType::DoomShroom => {
+                "Destroys a huge area and leaves a crater. Sleeps during the day."
+            }
+            PlantType::MagnetShroom => {
+                "Pulls buckets, helmets and other metal off zombies. Sleeps during the day."
+            }
+            PlantType::Garlic => "Zombies that bite it turn away into a neighbouring lane.",
+            PlantType::Starfruit => {
+                "Shoots stars in five directions: up, down, back and diagonally forward."
+            }
+            PlantType::TwinSunflower => {
+                "Produces two suns at a time. Must be planted on a Sunflower."
+            }
+            PlantType::WinterMelon => {
+                "Lobs frozen melons that splash and slow. Must be planted on a Melon-pult."
+            }
+            PlantType::GloomShroom => {
+                "Sprays fumes all around itself. Must be planted on a Fume-shroom. Sleeps during the day."
+            }
+            PlantType::CoffeeBean => "Plant it on a sleeping mushroom to wake it up.",
+            PlantType::Cactus => "Shoots spikes that can pop balloons and hit flying zombies.",
+            PlantType::Blover => "Blows every flying zombie off the lawn.",
+            PlantType::UmbrellaLeaf => {
+                "Shields plants around it from basketballs and bungee zombies."
+            }
         }
     }
 
@@ -34,6 +147,36 @@ impl PlantType {
             PlantType::PotatoMine => 25,
             PlantType::Wallnut => 50,
             PlantType::CherryBomb => 150,
+            PlantType::Repeater => 200,
+            PlantType::GatlingPea => 250,
+            PlantType::Threepeater => 325,
+            PlantType::SplitPea => 125,
+            PlantType::Chomper => 150,
+            PlantType::Squash => 50,
+            PlantType::Jalapeno => 125,
+            PlantType::TallNut => 125,
+            PlantType::Pumpkin => 125,
+            PlantType::Spikeweed => 100,
+            PlantType::Torchwood => 175,
+            PlantType::CabbagePult => 100,
+            PlantType::KernelPult => 100,
+            PlantType::MelonPult => 300,
+            PlantType::PuffShroom => 0,
+            PlantType::SunShroom => 25,
+            PlantType::FumeShroom => 75,
+            PlantType::ScaredyShroom => 25,
+            PlantType::IceShroom => 75,
+            PlantType::DoomShroom => 125,
+            PlantType::MagnetShroom => 100,
+            PlantType::Garlic => 50,
+            PlantType::Starfruit => 125,
+            PlantType::TwinSunflower => 150,
+            PlantType::WinterMelon => 200,
+            PlantType::GloomShroom => 150,
+            PlantType::CoffeeBean => 75,
+            PlantType::Cactus => 125,
+            PlantType::Blover => 100,
+            PlantType::UmbrellaLeaf => 100,
         }
     }
 
@@ -45,9 +188,82 @@ impl PlantType {
             PlantType::PotatoMine => 25.0,
             PlantType::Wallnut => 30.0,
             PlantType::CherryBomb => 30.0,
+            PlantType::Repeater => 7.5,
+            PlantType::GatlingPea => 50.0,
+            PlantType::Threepeater => 7.5,
+            PlantType::SplitPea => 7.5,
+            PlantType::Chomper => 7.5,
+            PlantType::Squash => 30.0,
+            PlantType::Jalapeno => 50.0,
+            PlantType::TallNut => 30.0,
+            PlantType::Pumpkin => 30.0,
+            PlantType::Spikeweed => 7.5,
+            PlantType::Torchwood => 7.5,
+            PlantType::CabbagePult => 7.5,
+            PlantType::KernelPult => 7.5,
+            PlantType::MelonPult => 7.5,
+            PlantType::PuffShroom => 7.5,
+            PlantType::SunShroom => 7.5,
+            PlantType::FumeShroom => 7.5,
+            PlantType::ScaredyShroom => 7.5,
+            PlantType::IceShroom => 50.0,
+            PlantType::DoomShroom => 50.0,
+            PlantType::MagnetShroom => 7.5,
+            PlantType::Garlic => 7.5,
+            PlantType::Starfruit => 7.5,
+            PlantType::TwinSunflower => 50.0,
+            PlantType::WinterMelon => 50.0,
+            PlantType::GloomShroom => 50.0,
+            PlantType::CoffeeBean => 7.5,
+            PlantType::Cactus => 7.5,
+            PlantType::Blover => 7.5,
+            PlantType::UmbrellaLeaf => 7.5,
+        }
+    }
+
+    /// Tile slot the plant goes into when placed
+    pub fn layer(&self) -> PlantLayer {
+        match self {
+            PlantType::Pumpkin => PlantLayer::Shell,
+            PlantType::Spikeweed => PlantLayer::Ground,
+            PlantType::CoffeeBean => PlantLayer::Top,
+            _ => PlantLayer::Main,
+        }
+    }
+
+    /// The plant this one has to be planted on, and replaces
+    pub fn upgrade_of(&self) -> Option<PlantType> {
+        match self {
+            PlantType::GatlingPea => Some(PlantType::Repeater),
+            PlantType::TwinSunflower => Some(PlantType::Sunflower),
+            PlantType::WinterMelon => Some(PlantType::MelonPult),
+            PlantType::GloomShroom => Some(PlantType::FumeShroom),
+            _ => None,
+        }
+    }
+
+    /// What the plant does to the plant it is placed on, if it is placed on one
+    pub fn effect_on_occupant(&self) -> Option<PlantEffect> {
+        match self {
+            PlantType::CoffeeBean => Some(PlantEffect::Wake),
+            _ => None,
         }
     }
 
+    /// Small arrow in the top-right corner (x, y) of an upgrade's seed card
+    pub fn draw_upgrade_marker(&self, x: f32, y: f32) {
+        if self.upgrade_of().is_none() {
+            return;
+        }
+        draw_triangle(
+            vec2(x - 14.0, y + 12.0),
+            vec2(x - 4.0, y + 12.0),
+            vec2(x - 9.0, y + 3.0),
+            GOLD,
+        );
+        draw_rectangle(x - 11.0, y + 12.0, 4.0, 5.0, GOLD);
+    }
+
     pub fn draw_preview(&self, x: f32, y: f32) {
         match self {
             PlantType::Peashooter => {
@@ -59,6 +275,156 @@ impl PlantType {
                 draw_circle(x - 4.0, y - 3.0, 1.2, BLACK);
                 draw_circle(x + 2.0, y - 3.0, 1.2, BLACK);
             }
+            PlantType::Repeater => {
+                draw_circle(x, y, 10.0, GREEN);
+                draw_circle(x + 10.0, y, 5.0, DARKGREEN);
+                draw_circle(x + 7.0, y - 5.0, 3.5, DARKGREEN);
+
+                // eyes
+                draw_circle(x - 4.0, y - 3.0, 1.2, BLACK);
+                draw_circle(x + 2.0, y - 3.0, 1.2, BLACK);
+            }
+            PlantType::GatlingPea => {
+                draw_circle(x, y, 10.0, DARKGREEN);
+                draw_circle(x + 10.0, y, 5.0, GRAY);
+                draw_rectangle(x + 9.0, y - 3.0, 7.0, 6.0, DARKGRAY);
+
+                // helmet
+                draw_ellipse(x, y - 8.0, 9.0, 4.0, 0.0, DARKGREEN);
+
+                // eyes
+                draw_circle(x - 4.0, y - 3.0, 1.2, BLACK);
+                draw_circle(x + 2.0, y - 3.0, 1.2, BLACK);
+            }
+            PlantType::Threepeater => {
+                draw_circle(x, y + 4.0, 8.0, GREEN);
+                for dy in [-8.0, 0.0, 8.0] {
+                    draw_circle(x + 5.0, y + dy - 2.0, 4.0, GREEN);
+                    draw_circle(x + 10.0, y + dy - 2.0, 2.5, DARKGREEN);
+                }
+            }
+            PlantType::SplitPea => {
+                draw_circle(x, y, 10.0, GREEN);
+                draw_circle(x + 10.0, y, 5.0, DARKGREEN);
+                draw_circle(x - 9.0, y - 4.0, 6.0, GREEN);
+                draw_circle(x - 15.0, y - 4.0, 3.5, DARKGREEN);
+
+                // eyes
+                draw_circle(x - 8.0, y - 6.0, 1.2, BLACK);
+                draw_circle(x + 2.0, y - 3.0, 1.2, BLACK);
+            }
+            PlantType::Chomper => {
+                draw_rectangle(x - 1.5, y, 3.0, 12.0, DARKGREEN);
+                draw_circle(x, y - 3.0, 11.0, PURPLE);
+                draw_triangle(
+                    vec2(x + 1.0, y - 3.0),
+                    vec2(x + 12.0, y - 10.0),
+                    vec2(x + 12.0, y + 5.0),
+                    MAROON,
+                );
+
+                // eye
+                draw_circle(x - 3.0, y - 7.0, 1.2, BLACK);
+            }
+            PlantType::Squash => {
+                draw_rectangle(x - 10.0, y - 9.0, 20.0, 19.0, DARKGREEN);
+                draw_rectangle(x - 8.0, y - 12.0, 16.0, 4.0, GREEN);
+
+                // eyes
+                draw_circle(x - 4.0, y - 2.0, 1.2, BLACK);
+                draw_circle(x + 4.0, y - 2.0, 1.2, BLACK);
+            }
+            PlantType::Jalapeno => {
+                draw_ellipse(x, y + 2.0, 6.0, 12.0, 0.0, RED);
+                draw_rectangle(x - 1.5, y - 13.0, 3.0, 4.0, DARKGREEN);
+
+                // eyes
+                draw_circle(x - 2.0, y - 3.0, 1.0, BLACK);
+                draw_circle(x + 2.0, y - 3.0, 1.0, BLACK);
+            }
+            PlantType::TallNut => {
+                draw_ellipse(x, y - 2.0, 9.0, 15.0, 0.0, DARKBROWN);
+
+                // eyes
+                draw_circle(x - 3.0, y - 4.0, 1.2, BLACK);
+                draw_circle(x + 3.0, y - 4.0, 1.2, BLACK);
+            }
+            PlantType::Pumpkin => {
+                draw_ellipse(x, y + 2.0, 14.0, 10.0, 0.0, ORANGE);
+                draw_line(x, y - 8.0, x, y - 12.0, 2.0, DARKGREEN);
+            }
+            PlantType::Spikeweed => {
+                draw_ellipse(x, y + 6.0, 14.0, 4.0, 0.0, DARKGREEN);
+                for dx in [-9.0, -3.0, 3.0, 9.0] {
+                    draw_triangle(
+                        vec2(x + dx - 2.5, y + 5.0),
+                        vec2(x + dx + 2.5, y + 5.0),
+                        vec2(x + dx, y - 3.0),
+                        GRAY,
+                    );
+                }
+            }
+            PlantType::Torchwood => {
+                draw_rectangle(x - 8.0, y - 4.0, 16.0, 14.0, BROWN);
+                draw_triangle(
+                    vec2(x - 7.0, y - 4.0),
+                    vec2(x + 7.0, y - 4.0),
+                    vec2(x, y - 16.0),
+                    ORANGE,
+                );
+
+                // eyes
+                draw_circle(x - 3.0, y + 2.0, 1.2, BLACK);
+                draw_circle(x + 3.0, y + 2.0, 1.2, BLACK);
+            }
+            PlantType::CabbagePult => {
+                draw_circle(x, y + 3.0, 9.0, LIME);
+                draw_line(x, y - 2.0, x - 9.0, y - 11.0, 2.0, DARKGREEN);
+                draw_circle(x - 10.0, y - 13.0, 3.5, GREEN);
+            }
+            PlantType::KernelPult => {
+                draw_ellipse(x, y + 2.0, 7.0, 10.0, 0.0, GOLD);
+                draw_line(x, y - 2.0, x - 9.0, y - 11.0, 2.0, DARKGREEN);
+                draw_circle(x - 10.0, y - 12.0, 2.0, YELLOW);
+            }
+            PlantType::MelonPult => {
+                draw_circle(x, y + 3.0, 10.0, GREEN);
+                draw_line(x, y - 2.0, x - 9.0, y - 11.0, 2.0, DARKGREEN);
+                draw_circle(x - 11.0, y - 14.0, 5.0, DARKGREEN);
+            }
+            PlantType::PuffShroom => {
+                draw_rectangle(x - 2.5, y + 2.0, 5.0, 6.0, BEIGE);
+                draw_ellipse(x, y + 2.0, 7.0, 5.0, 0.0, VIOLET);
+            }
+            PlantType::SunShroom => {
+                draw_rectangle(x - 3.0, y, 6.0, 9.0, BEIGE);
+                draw_ellipse(x, y, 10.0, 7.0, 0.0, GOLD);
+            }
+            PlantType::FumeShroom => {
+                draw_rectangle(x - 4.0, y, 8.0, 9.0, BEIGE);
+                draw_ellipse(x, y - 1.0, 11.0, 8.0, 0.0, PURPLE);
+                draw_rectangle(x + 6.0, y - 3.0, 6.0, 5.0, DARKPURPLE);
+            }
+            PlantType::ScaredyShroom => {
+                draw_rectangle(x - 2.0, y - 5.0, 4.0, 14.0, BEIGE);
+                draw_ellipse(x, y - 7.0, 8.0, 6.0, 0.0, PINK);
+            }
+            PlantType::IceShroom => {
+                draw_rectangle(x - 3.0, y, 6.0, 9.0, BEIGE);
+                draw_ellipse(x, y - 1.0, 10.0, 7.0, 0.0, SKYBLUE);
+                draw_circle(x - 4.0, y - 3.0, 1.5, WHITE);
+            }
+            PlantType::DoomShroom => {
+                draw_rectangle(x - 3.0, y, 6.0, 9.0, GRAY);
+                draw_ellipse(x, y - 1.0, 10.0, 7.0, 0.0, DARKPURPLE);
+                draw_circle(x - 4.0, y - 3.0, 1.5, PURPLE);
+            }
+            PlantType::MagnetShroom => {
+                draw_rectangle(x - 3.0, y, 6.0, 9.0, BEIGE);
+                draw_ellipse(x, y - 1.0, 10.0, 7.0, 0.0, PURPLE);
+                draw_rectangle(x - 5.0, y - 11.0, 3.0, 7.0, RED);
+                draw_rectangle(x + 2.0, y - 11.0, 3.0, 7.0, RED);
+            }
             PlantType::SlowPeashooter => {
                 draw_circle(x, y, 10.0, BLUE);
                 draw_circle(x + 10.0, y, 5.0, DARKBLUE);
@@ -147,17 +513,98 @@ impl PlantType {
                     BLACK,
                 ); // mouth
             }
+            PlantType::Garlic => {
+                draw_circle(x, y + 2.0, 9.0, WHITE);
+                draw_triangle(
+                    vec2(x - 3.0, y - 5.0),
+                    vec2(x + 3.0, y - 5.0),
+                    vec2(x, y - 12.0),
+                    WHITE,
+                );
+            }
+            PlantType::Starfruit => {
+                draw_poly(x, y, 5, 11.0, -90.0, GOLD);
+
+                // eyes
+                draw_circle(x - 2.5, y - 1.5, 1.0, BLACK);
+                draw_circle(x + 2.5, y - 1.5, 1.0, BLACK);
+            }
+            PlantType::TwinSunflower => {
+                for dx in [-6.0, 6.0] {
+                    draw_circle(x + dx, y, 7.0, YELLOW);
+                    draw_circle(x + dx, y, 4.0, ORANGE);
+                }
+            }
+            PlantType::WinterMelon => {
+                draw_circle(x, y + 3.0, 10.0, SKYBLUE);
+                draw_line(x, y - 2.0, x - 9.0, y - 11.0, 2.0, DARKGREEN);
+                draw_circle(x - 11.0, y - 14.0, 5.0, BLUE);
+            }
+            PlantType::GloomShroom => {
+                draw_rectangle(x - 4.0, y, 8.0, 9.0, GRAY);
+                draw_ellipse(x, y - 1.0, 12.0, 8.0, 0.0, DARKPURPLE);
+            }
+            PlantType::CoffeeBean => {
+                draw_ellipse(x, y, 6.0, 9.0, 0.0, DARKBROWN);
+                draw_line(x, y - 7.0, x, y + 7.0, 1.5, BROWN);
+            }
+            PlantType::Cactus => {
+                draw_rectangle(x - 5.0, y - 10.0, 10.0, 20.0, GREEN);
+                draw_circle(x, y - 10.0, 5.0, GREEN);
+                draw_rectangle(x - 10.0, y - 2.0, 5.0, 3.0, GREEN);
+                draw_rectangle(x + 5.0, y - 5.0, 5.0, 3.0, GREEN);
+            }
+            PlantType::Blover => {
+                draw_rectangle(x - 1.0, y, 2.0, 10.0, DARKGREEN);
+                for (dx, dy) in [(-5.0, -5.0), (5.0, -5.0), (-5.0, 5.0), (5.0, 5.0)] {
+                    draw_circle(x + dx, y + dy, 4.5, GREEN);
+                }
+            }
+            PlantType::UmbrellaLeaf => {
+                draw_rectangle(x - 1.0, y - 4.0, 2.0, 14.0, DARKGREEN);
+                draw_ellipse(x, y - 6.0, 12.0, 6.0, 0.0, GREEN);
+            }
         }
     }
 }
 
-pub fn create_plant(plant_type: PlantType, x: f32, y: f32) -> Box<dyn Plant> {
+pub fn create_plant(plant_type: PlantType, x: f32, y: f32, row: usize) -> Box<dyn Plant> {
     match plant_type {
-        PlantType::Peashooter => Box::new(Peashooter::new(x, y)),
-        PlantType::SlowPeashooter => Box::new(SlowPeashooter::new(x, y)),
-        PlantType::Sunflower => Box::new(Sunflower::new(x, y)),
-        PlantType::PotatoMine => Box::new(PotatoMine::new(x, y)),
-        PlantType::Wallnut => Box::new(Wallnut::new(x, y)),
-        PlantType::CherryBomb => Box::new(CherryBomb::new(x, y)),
+        PlantType::Peashooter => Box::new(Peashooter::new(x, y, row)),
+        PlantType::SlowPeashooter => Box::new(SlowPeashooter::new(x, y, row)),
+        PlantType::Sunflower => Box::new(Sunflower::new(x, y, row)),
+        PlantType::PotatoMine => Box::new(PotatoMine::new(x, y, row)),
+        PlantType::Wallnut => Box::new(Wallnut::new(x, y, row)),
+        PlantType::CherryBomb => Box::new(CherryBomb::new(x, y, row)),
+        PlantType::Repeater => Box::new(Repeater::new(x, y, row)),
+        PlantType::GatlingPea => Box::new(GatlingPea::new(x, y, row)),
+        PlantType::Threepeater => Box::new(Threepeater::new(x, y, row)),
+        PlantType::SplitPea => Box::new(SplitPea::new(x, y, row)),
+        PlantType::Chomper => Box::new(Chomper::new(x, y, row)),
+        PlantType::Squash => Box::new(Squash::new(x, y, row)),
+        PlantType::Jalapeno => Box::new(Jalapeno::new(x, y, row)),
+        PlantType::TallNut => Box::new(TallNut::new(x, y, row)),
+        PlantType::Pumpkin => Box::new(Pumpkin::new(x, y, row)),
+        PlantType::Spikeweed => Box::new(Spikeweed::new(x, y, row)),
+        PlantType::Torchwood => Box::new(Torchwood::new(x, y, row)),
+        PlantType::CabbagePult => Box::new(CabbagePult::new(x, y, row)),
+        PlantType::KernelPult => Box::new(KernelPult::new(x, y, row)),
+        PlantType::MelonPult => Box::new(MelonPult::new(x, y, row)),
+        PlantType::PuffShroom => Box::new(PuffShroom::new(x, y, row)),
+        PlantType::SunShroom => Box::new(SunShroom::new(x, y, row)),
+        PlantType::FumeShroom => Box::new(FumeShroom::new(x, y, row)),
+        PlantType::ScaredyShroom => Box::new(ScaredyShroom::new(x, y, row)),
+        PlantType::IceShroom => Box::new(IceShroom::new(x, y, row)),
+        PlantType::DoomShroom => Box::new(DoomShroom::new(x, y, row)),
+        PlantType::MagnetShroom => Box::new(MagnetShroom::new(x, y, row)),
+        PlantType::Garlic => Box::new(Garlic::new(x, y, row)),
+        PlantType::Starfruit => Box::new(Starfruit::new(x, y, row)),
+        PlantType::TwinSunflower => Box::new(TwinSunflower::new(x, y, row)),
+        PlantType::WinterMelon => Box::new(WinterMelon::new(x, y, row)),
+        PlantType::GloomShroom => Box::new(GloomShroom::new(x, y, row)),
+        PlantType::CoffeeBean => Box::new(CoffeeBean::new(x, y, row)),
+        PlantType::Cactus => Box::new(Cactus::new(x, y, row)),
+        PlantType::Blover => Box::new(Blover::new(x, y, row)),
+        PlantType::UmbrellaLeaf => Box::new(UmbrellaLeaf::new(x, y, row)),
     }
 }
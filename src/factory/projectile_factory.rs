@@ -1,27 +1,45 @@
+use crate::factory::plant_factory::PlantType;
 use crate::projectile::{
     instakill_explode::InstakillExplode,
-    normal_pea::NormalPea,
-    projectile::{Instakill, Projectile},
-    slow_pea::SlowPea,
+    lobbed::{LobKind, Lobbed},
+    pea::{Pea, PeaKind},
+    projectile::{BlastShape, Direction, Instakill, Projectile},
+    star::Star,
 };
+use macroquad::prelude::Vec2;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ProjectileKind {
-    Normal,
-    Slow,
-    Instakill { radius: f32, tier: Instakill },
+    Pea(PeaKind),
+    Lobbed(LobKind),
+    Instakill {
+        shape: BlastShape,
+        tier: Instakill,
+    },
+    /// Free-flying star; `heading` replaces the lane and direction
+    Star {
+        heading: Vec2,
+    },
 }
 
 pub struct ProjectileFactory;
 
 impl ProjectileFactory {
-    pub fn create(kind: ProjectileKind, x: f32, y: f32) -> Box<dyn Projectile> {
+    pub fn create(
+        kind: ProjectileKind,
+        source: PlantType,
+        x: f32,
+        y: f32,
+        row: usize,
+        direction: Direction,
+    ) -> Box<dyn Projectile> {
         match kind {
-            ProjectileKind::Normal => Box::new(NormalPea::new(x, y)),
-            ProjectileKind::Slow => Box::new(SlowPea::new(x, y)),
-            ProjectileKind::Instakill { radius, tier } => {
-                Box::new(InstakillExplode::new(x, y, radius, tier))
+            ProjectileKind::Pea(pea) => Box::new(Pea::new(x, y, row, pea, direction, source)),
+            ProjectileKind::Lobbed(lob) => Box::new(Lobbed::new(x, y, row, lob, source)),
+            ProjectileKind::Instakill { shape, tier } => {
+                Box::new(InstakillExplode::new(x, y, row, shape, tier, source))
             }
+            ProjectileKind::Star { heading } => Box::new(Star::new(x, y, heading, source)),
         }
     }
 }
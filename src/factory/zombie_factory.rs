@@ -1,8 +1,10 @@
 use crate::zombie::{
-    basic_zombie::BasicZombie, buckethead_zombie::BucketheadZombie,
-    conehead_zombie::ConeheadZombie, zombie::Zombie,
+    balloon_zombie::BalloonZombie, basic_zombie::BasicZombie, buckethead_zombie::BucketheadZombie,
+    bungee_zombie::BungeeZombie, catapult_zombie::CatapultZombie, conehead_zombie::ConeheadZombie,
+    screen_door_zombie::ScreenDoorZombie, zombie::Zombie,
 };
 use macroquad::rand::{self, ChooseRandom}; // <-- use macroquad RNG + trait for choose()
+use strum_macros::EnumIter;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Rarity {
@@ -13,20 +15,44 @@ pub enum Rarity {
     Boss,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter)]
 pub enum ZombieType {
     Basic,
     Conehead,
     Buckethead,
     PoleZombie,
+    ScreenDoor,
+    Balloon,
+    Catapult,
+    Bungee,
+}
+
+impl ZombieType {
+    /// Price of this zombie for point-budget wave generation
+    pub fn cost(&self) -> u32 {
+        match self {
+            ZombieType::Basic => 1,
+            ZombieType::Conehead => 2,
+            ZombieType::PoleZombie => 2,
+            ZombieType::ScreenDoor => 3,
+            ZombieType::Balloon => 3,
+            ZombieType::Buckethead => 4,
+            ZombieType::Catapult => 4,
+            ZombieType::Bungee => 4,
+        }
+    }
 }
 
-pub fn create_zombie(zombie_type: ZombieType, y: f32) -> Box<dyn Zombie> {
+pub fn create_zombie(zombie_type: ZombieType, row: usize) -> Box<dyn Zombie> {
     match zombie_type {
-        ZombieType::Basic => Box::new(BasicZombie::new(y)),
-        ZombieType::Conehead => Box::new(ConeheadZombie::new(y)),
-        ZombieType::Buckethead => Box::new(BucketheadZombie::new(y)),
-        ZombieType::PoleZombie => Box::new(crate::zombie::pole_zombie::PoleZombie::new(y)),
+        ZombieType::Basic => Box::new(BasicZombie::new(row)),
+        ZombieType::Conehead => Box::new(ConeheadZombie::new(row)),
+        ZombieType::Buckethead => Box::new(BucketheadZombie::new(row)),
+        ZombieType::PoleZombie => Box::new(crate::zombie::pole_zombie::PoleZombie::new(row)),
+        ZombieType::ScreenDoor => Box::new(ScreenDoorZombie::new(row)),
+        ZombieType::Balloon => Box::new(BalloonZombie::new(row)),
+        ZombieType::Catapult => Box::new(CatapultZombie::new(row)),
+        ZombieType::Bungee => Box::new(BungeeZombie::new(row)),
     }
 }
 
@@ -62,7 +88,7 @@ fn get_scaled_rarity_weights(zombie_count: usize) -> Vec<(Rarity, f32)> {
     weights
 }
 
-fn pick_rarity(zombie_count: usize) -> Rarity {
+pub fn pick_rarity(zombie_count: usize) -> Rarity {
     let roll: f32 = rand::gen_range(0.0, 1.0);
     let weights = get_scaled_rarity_weights(zombie_count);
 
@@ -76,21 +102,25 @@ fn pick_rarity(zombie_count: usize) -> Rarity {
     Rarity::Common
 }
 
-fn zombies_by_rarity(rarity: Rarity) -> Vec<ZombieType> {
+pub fn zombies_by_rarity(rarity: Rarity) -> Vec<ZombieType> {
     match rarity {
         Rarity::Common => vec![ZombieType::Basic],
         Rarity::Uncommon => vec![ZombieType::Conehead, ZombieType::PoleZombie],
-        Rarity::Rare => vec![ZombieType::Conehead],
-        Rarity::Epic => vec![ZombieType::Buckethead],
+        Rarity::Rare => vec![
+            ZombieType::Conehead,
+            ZombieType::ScreenDoor,
+            ZombieType::Balloon,
+            ZombieType::Bungee,
+        ],
+        Rarity::Epic => vec![ZombieType::Buckethead, ZombieType::Catapult],
         Rarity::Boss => vec![ZombieType::Buckethead], // later: add Boss type
     }
 }
 
-pub fn spawn_random_zombie(y: f32, zombie_count: usize) -> Box<dyn Zombie> {
+pub fn random_zombie_type(zombie_count: usize) -> ZombieType {
     let rarity = pick_rarity(zombie_count);
     let pool = zombies_by_rarity(rarity);
 
     // `choose` comes from macroquad::rand::ChooseRandom
-    let zombie_type = *pool.choose().unwrap_or(&ZombieType::Basic);
-    create_zombie(zombie_type, y)
+    *pool.choose().unwrap_or(&ZombieType::Basic)
 }
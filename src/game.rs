@@ -1,59 +1,126 @@
-use std::cmp::min;
-
+use crate::achievement::AchievementTracker;
+use crate::campaign::{LEVELS, TimeOfDay};
 use crate::constants::*;
+use crate::event::{EventListener, EventQueue, GameEvent};
 use crate::factory::plant_factory::{PlantType, create_plant};
-use crate::factory::projectile_factory::ProjectileFactory;
-use crate::factory::zombie_factory::spawn_random_zombie;
+use crate::factory::projectile_factory::{ProjectileFactory, ProjectileKind};
+use crate::factory::zombie_factory::create_zombie;
+use crate::game_state::GameMode;
 use crate::grid::Grid;
-use crate::plant::plant::{Plant, PlantAction};
+use crate::plant::plant::{Plant, PlantAction, PlantLayer};
 use crate::plant_bar::UIBar;
-use crate::projectile::projectile::Projectile;
-use crate::sun::Sun;
+use crate::profile::Profile;
+use crate::projectile::projectile::{Direction, Projectile};
+use crate::spawner::{
+    classic_spawner::ClassicSpawner, scripted_spawner::ScriptedSpawner, spawner::Spawner,
+    survival_spawner::SurvivalSpawner,
+};
+use crate::stats::MatchStats;
+use crate::sun::{Sun, SunSource};
+use crate::toast::Toasts;
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
 
+/// What planting on a tile would do
+enum Placement {
+    Empty,
+    /// Replaces the plant at this index
+    Upgrade(usize),
+    Invalid,
+}
+
+/// A projectile waiting out its burst delay before it is fired
+pub struct PendingShot {
+    pub delay: f32,
+    pub kind: ProjectileKind,
+    pub source: PlantType,
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub direction: Direction,
+}
+
 pub struct Game {
     pub grid: Grid,
     pub plant_bar: UIBar,
     pub plants: Vec<Box<dyn Plant>>,
     pub projectiles: Vec<Box<dyn Projectile>>,
+    pub pending_shots: Vec<PendingShot>,
     pub suns: Vec<Sun>,
     pub sun_points: i32,
     pub natural_sun_timer: f32,
     pub next_natural_sun_time: f32,
     pub zombies: Vec<Box<dyn Zombie>>,
-    pub zombie_timer: f32,
-    pub next_zombie_time: f32,
-    pub zombie_count: i32,
+    pub mode: GameMode,
+    pub time_of_day: TimeOfDay,
+    pub spawner: Box<dyn Spawner>,
+    pub round: u32,
+    pub round_cleared: bool,
+    pub events: EventQueue,
+    pub lost: bool,
+    pub stats: MatchStats,
+    pub achievements: AchievementTracker,
+    pub toasts: Toasts,
+    pub banner: Option<(String, f32)>,
 }
 
 impl Game {
-    pub fn new(selected_plants: Vec<PlantType>) -> Self {
+    pub fn new(selected_plants: Vec<PlantType>, mode: GameMode, profile: &Profile) -> Self {
+        let (spawner, starting_sun, time_of_day): (Box<dyn Spawner>, i32, TimeOfDay) = match mode {
+            GameMode::QuickPlay => (Box::new(ClassicSpawner::new()), 50, TimeOfDay::Day),
+            GameMode::Survival => (Box::new(SurvivalSpawner::new(1)), 50, TimeOfDay::Day),
+            GameMode::Adventure(level) => {
+                let level = &LEVELS[level];
+                (
+                    Box::new(ScriptedSpawner::new(level.waves)),
+                    level.starting_sun,
+                    level.time_of_day,
+                )
+            }
+        };
+
         Game {
             grid: Grid::new(),
             plant_bar: UIBar::new(selected_plants),
             plants: Vec::new(),
             projectiles: Vec::new(),
+            pending_shots: Vec::new(),
             suns: Vec::new(),
-            sun_points: 50,
+            sun_points: starting_sun,
             natural_sun_timer: 0.0,
             next_natural_sun_time: rand::gen_range(
                 NATURAL_SUN_MIN_INTERVAL,
                 NATURAL_SUN_MAX_INTERVAL,
             ),
             zombies: Vec::new(),
-            zombie_timer: 0.0,
-            next_zombie_time: rand::gen_range(
-                NATURAL_ZOMBIE_MIN_SPAWN_INTERVAL,
-                NATURAL_ZOMBIE_MAX_SPAWN_INTERVAL,
-            ),
-            zombie_count: 0,
+            mode,
+            time_of_day,
+            spawner,
+            round: 1,
+            round_cleared: false,
+            events: EventQueue::new(),
+            lost: false,
+            stats: MatchStats::new(),
+            achievements: AchievementTracker::new(profile.achievements.clone()),
+            toasts: Toasts::new(),
+            banner: None,
         }
     }
 
+    /// Starts the next Survival round with a fresh loadout; the lawn is kept
+    pub fn next_round(&mut self, selected_plants: Vec<PlantType>) {
+        self.round += 1;
+        self.round_cleared = false;
+        self.plant_bar = UIBar::new(selected_plants);
+        self.spawner = Box::new(SurvivalSpawner::new(self.round));
+        self.banner = Some((format!("Round {}", self.round), 3.0));
+    }
+
     pub fn update(&mut self) {
         let dt = get_frame_time();
+        self.stats.time_survived += dt;
         self.plant_bar.update();
+        self.grid.update(dt);
 
         let mouse: Vec2 = mouse_position().into();
 
@@ -61,67 +128,125 @@ impl Game {
         for sun in &mut self.suns {
             if sun.is_hovered(mouse.x, mouse.y) {
                 self.sun_points += sun.value;
+                self.events.push(GameEvent::SunCollected {
+                    value: sun.value,
+                    source: sun.source,
+                });
             }
         }
 
         // --- place plant logic ---
         if is_mouse_button_pressed(MouseButton::Left) {
-            if let Some(plant_type) = self.plant_bar.selected {
-                if self.sun_points >= plant_type.cost() {
-                    if let Some(tile) = self.grid.get_tile_at(mouse) {
-                        let x = tile.x + TILE_SIZE / 2.0;
-                        let y = tile.y + TILE_SIZE / 2.0;
-
-                        // check if tile is already occupied
-                        let occupied = self.plants.iter().any(|p| {
-                            (p.x() - x).abs() < TILE_SIZE * 0.5
-                                && (p.y() - y).abs() < TILE_SIZE * 0.5
-                        });
-
-                        if !occupied {
-                            if let Some(slot) = self
-                                .plant_bar
-                                .slots
-                                .iter_mut()
-                                .find(|s| s.plant == plant_type)
-                            {
-                                if slot.cooldown <= 0.0 {
-                                    // place plant using factory
-                                    self.sun_points -= plant_type.cost();
-                                    self.plants.push(create_plant(plant_type, x, y));
-
-                                    // start cooldown
-                                    slot.cooldown = plant_type.cooldown_time();
-
-                                    // clear selection
-                                    self.plant_bar.selected = None;
-                                    for s in &mut self.plant_bar.slots {
-                                        s.selected = false;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+            self.try_place_plant(mouse);
         }
 
         // --- update plants ---
+        // effects on neighbours wait until every plant has been updated
+        let mut occupant_effects = Vec::new();
         for plant in &mut self.plants {
-            if let Some(action) = plant.update(dt, &self.zombies) {
+            if plant.is_sleeping() {
+                continue;
+            }
+            for action in plant.update(dt, &self.zombies) {
                 match action {
-                    PlantAction::Shoot { kind, x, y } => {
-                        let proj = ProjectileFactory::create(kind, x, y);
-                        self.projectiles.push(proj);
+                    PlantAction::Shoot {
+                        kind,
+                        x,
+                        y,
+                        row,
+                        direction,
+                        delay,
+                    } => {
+                        self.pending_shots.push(PendingShot {
+                            delay,
+                            kind,
+                            source: plant.plant_type(),
+                            x,
+                            y,
+                            row,
+                            direction,
+                        });
+                    }
+                    PlantAction::Devour { target } => {
+                        if let Some(zombie) = self.zombies.get_mut(target) {
+                            zombie.swallow();
+                        }
+                    }
+                    PlantAction::Damage { target, amount } => {
+                        if let Some(zombie) = self.zombies.get_mut(target) {
+                            zombie.take_piercing_damage(amount);
+                            self.events.push(GameEvent::ProjectileHit {
+                                source: plant.plant_type(),
+                                target: zombie.zombie_type(),
+                                damage: amount,
+                                x: zombie.x(),
+                                y: zombie.y(),
+                            });
+                        }
+                    }
+                    PlantAction::FreezeAll { duration, chill } => {
+                        for zombie in &mut self.zombies {
+                            zombie.apply_freeze(duration);
+                            zombie.apply_slow(duration + chill);
+                        }
+                    }
+                    PlantAction::BlowAway => {
+                        for zombie in &mut self.zombies {
+                            zombie.blow_away();
+                        }
+                    }
+                    PlantAction::Crater { x, y, duration } => {
+                        self.grid.block_tile_at(vec2(x, y), duration);
+                    }
+                    PlantAction::AffectOccupant { x, y, effect } => {
+                        occupant_effects.push((vec2(x, y), plant.layer(), effect));
+                    }
+                    PlantAction::PullAccessory { target } => {
+                        if let Some(zombie) = self.zombies.get_mut(target) {
+                            zombie.remove_accessory();
+                        }
+                    }
+                    PlantAction::ProduceSun { x, y, value } => {
+                        let sun = Sun::from_plant(x, y, value);
+                        self.events.push(GameEvent::SunProduced {
+                            value: sun.value,
+                            source: SunSource::Plant,
+                        });
+                        self.suns.push(sun);
                     }
-                    PlantAction::ProduceSun { x, y } => self.suns.push(Sun::from_plant(x, y)),
                 }
             }
         }
+        for (pos, layer, effect) in occupant_effects {
+            for plant in &mut self.plants {
+                if plant.layer() != layer
+                    && (plant.x() - pos.x).abs() < TILE_SIZE * 0.5
+                    && (plant.y() - pos.y).abs() < TILE_SIZE * 0.5
+                {
+                    effect.apply(plant.as_mut());
+                }
+            }
+        }
+
+        // --- fire queued shots ---
+        for shot in &mut self.pending_shots {
+            shot.delay -= dt;
+            if shot.delay <= 0.0 {
+                self.projectiles.push(ProjectileFactory::create(
+                    shot.kind,
+                    shot.source,
+                    shot.x,
+                    shot.y,
+                    shot.row,
+                    shot.direction,
+                ));
+            }
+        }
+        self.pending_shots.retain(|s| s.delay > 0.0);
 
         // --- update projectiles ---
         for proj in &mut self.projectiles {
-            proj.update(dt, &mut self.zombies);
+            proj.update(dt, &self.plants, &mut self.zombies, &mut self.events);
         }
         self.projectiles.retain(|p| p.is_active());
 
@@ -136,72 +261,194 @@ impl Game {
 
         // --- update zombies ---
         for zombie in &mut self.zombies {
-            zombie.update(&mut self.plants);
+            zombie.update(&mut self.plants, &mut self.events);
+            if zombie.is_dead() {
+                self.events.push(GameEvent::ZombieDied {
+                    zombie_type: zombie.zombie_type(),
+                    x: zombie.x(),
+                    y: zombie.y(),
+                });
+            } else if !zombie.is_gone() && zombie.x() < 0.0 {
+                self.events
+                    .push(GameEvent::LaneBreached { row: zombie.row() });
+            }
         }
-        self.zombies.retain(|z| !z.is_dead());
+        self.zombies.retain(|z| !z.is_dead() && !z.is_gone());
 
         // --- spawn natural suns ---
         self.natural_sun_timer += dt;
-        if self.natural_sun_timer >= self.next_natural_sun_time {
-            self.suns.push(Sun::natural());
+        if self.time_of_day == TimeOfDay::Day
+            && self.natural_sun_timer >= self.next_natural_sun_time
+        {
+            let sun = Sun::natural();
+            self.events.push(GameEvent::SunProduced {
+                value: sun.value,
+                source: SunSource::Natural,
+            });
+            self.suns.push(sun);
             self.natural_sun_timer = 0.0;
             self.next_natural_sun_time =
                 rand::gen_range(NATURAL_SUN_MIN_INTERVAL, NATURAL_SUN_MAX_INTERVAL);
         }
 
-        self.zombie_timer += dt;
-        if self.zombie_timer >= self.next_zombie_time {
-            let spawn_amount = min(self.zombie_count / 5 + 1, MAX_ZOMBIE_SPAWN);
-            let mut chosen_lanes = Vec::new();
+        // --- spawn zombies ---
+        for order in self
+            .spawner
+            .update(dt, self.zombies.len(), &mut self.events)
+        {
+            let zombie = create_zombie(order.zombie_type, order.row);
+            self.events.push(GameEvent::ZombieSpawned {
+                zombie_type: order.zombie_type,
+                row: order.row,
+            });
+            self.zombies.push(zombie);
+        }
 
-            for _ in 0..rand::gen_range(1, spawn_amount + 1) {
-                let mut lane;
-                let mut attempts = 0;
-                loop {
-                    lane = rand::gen_range(0, ROWS);
-                    attempts += 1;
-                    if !chosen_lanes.contains(&lane) || attempts > ROWS {
-                        break;
-                    }
-                }
+        // --- end of round ---
+        if !self.round_cleared && self.spawner.is_finished() && self.zombies.is_empty() {
+            self.round_cleared = true;
+            self.events
+                .push(GameEvent::RoundCleared { round: self.round });
+        }
 
-                if !chosen_lanes.contains(&lane) {
-                    chosen_lanes.push(lane);
+        // --- dispatch gameplay events ---
+        for event in self.events.drain() {
+            self.stats.on_event(&event);
+            self.achievements.check(&event, &self.stats);
+            self.on_event(&event);
+        }
 
-                    let y = lane as f32 * TILE_SIZE + TILE_SIZE / 2.0 + UI_BAR_HEIGHT;
+        // --- achievement toasts ---
+        for achievement in self.achievements.take_announcements() {
+            self.toasts.push(
+                &format!("Achievement: {}", achievement.name),
+                achievement.description,
+            );
+        }
+        self.toasts.update(dt);
 
-                    self.zombies.push(spawn_random_zombie(
-                        y,
-                        self.zombie_count.try_into().unwrap(),
-                    ));
-                    self.zombie_count += 1;
-                }
+        if let Some((_, timer)) = &mut self.banner {
+            *timer -= dt;
+            if *timer <= 0.0 {
+                self.banner = None;
             }
+        }
+    }
 
-            self.zombie_timer = 0.0;
-            self.next_zombie_time = rand::gen_range(
-                (NATURAL_ZOMBIE_MIN_SPAWN_INTERVAL - (self.zombie_count as f32 / 50.0)).max(2.0),
-                (NATURAL_ZOMBIE_MAX_SPAWN_INTERVAL - (self.zombie_count as f32 / 50.0)).max(4.0),
-            );
+    fn try_place_plant(&mut self, mouse: Vec2) {
+        let Some(slot_index) = self.plant_bar.selected else {
+            return;
+        };
+        let plant_type = self.plant_bar.slots[slot_index].plant;
+        if self.sun_points < plant_type.cost() {
+            return;
+        }
+        let Some(tile) = self.grid.get_tile_at(mouse) else {
+            return;
+        };
+        if tile.is_blocked() {
+            return;
+        }
+
+        let x = tile.x + TILE_SIZE / 2.0;
+        let y = tile.y + TILE_SIZE / 2.0;
+
+        let replaces = match self.placement(plant_type, x, y) {
+            Placement::Empty => None,
+            Placement::Upgrade(i) => Some(i),
+            Placement::Invalid => return,
+        };
+
+        let slot = &mut self.plant_bar.slots[slot_index];
+        if slot.cooldown > 0.0 {
+            return;
+        }
+
+        // place plant using factory
+        self.sun_points -= plant_type.cost();
+        if let Some(i) = replaces {
+            self.plants.remove(i);
+        }
+        let mut plant = create_plant(plant_type, x, y, tile.row);
+        if self.time_of_day == TimeOfDay::Day {
+            plant.sleep();
+        }
+        self.plants.push(plant);
+        self.events.push(GameEvent::PlantPlaced {
+            plant_type,
+            row: tile.row,
+            col: tile.col,
+        });
+
+        // start cooldown
+        slot.cooldown = plant_type.cooldown_time();
+
+        // clear selection
+        self.plant_bar.selected = None;
+        for s in &mut self.plant_bar.slots {
+            s.selected = false;
         }
     }
 
-    pub fn is_lost(&self) -> bool {
-        for zombie in &self.zombies {
-            if zombie.x() < 0.0 {
-                return true;
+    /// Whether `plant_type` can go on the tile centred at (x, y): upgrades only onto
+    /// the plant they upgrade, effect plants only onto a plant they'd affect,
+    /// everything else only where its layer is free
+    fn placement(&self, plant_type: PlantType, x: f32, y: f32) -> Placement {
+        // plants that act on another need something on the tile for it to work on
+        if let Some(effect) = plant_type.effect_on_occupant() {
+            let target = self.plants.iter().any(|p| {
+                (p.x() - x).abs() < TILE_SIZE * 0.5
+                    && (p.y() - y).abs() < TILE_SIZE * 0.5
+                    && effect.accepts(p.as_ref())
+            });
+            if !target || self.plant_at(x, y, plant_type.layer()).is_some() {
+                return Placement::Invalid;
             }
+            return Placement::Empty;
         }
-        false
+
+        let occupant = self.plant_at(x, y, plant_type.layer());
+        match (plant_type.upgrade_of(), occupant) {
+            (Some(base), Some(i)) if self.plants[i].plant_type() == base => Placement::Upgrade(i),
+            (Some(_), _) | (None, Some(_)) => Placement::Invalid,
+            (None, None) => Placement::Empty,
+        }
+    }
+
+    /// Index of a plant on the tile centred at (x, y) that a plant in `layer` can't share it with
+    fn plant_at(&self, x: f32, y: f32, layer: PlantLayer) -> Option<usize> {
+        self.plants.iter().position(|p| {
+            !layer.shares_tile_with(p.layer())
+                && (p.x() - x).abs() < TILE_SIZE * 0.5
+                && (p.y() - y).abs() < TILE_SIZE * 0.5
+        })
+    }
+
+    /// Adventure levels are won once their script has played out and the lawn is clear
+    pub fn is_won(&self) -> bool {
+        self.round_cleared && matches!(self.mode, GameMode::Adventure(_))
+    }
+
+    pub fn is_lost(&self) -> bool {
+        self.lost
     }
 
     pub fn draw(&self) {
-        clear_background(SKYBLUE);
+        clear_background(match self.time_of_day {
+            TimeOfDay::Day => SKYBLUE,
+            TimeOfDay::Night => DARKBLUE,
+        });
         self.grid.draw();
         self.plant_bar.draw(self.sun_points);
 
-        for plant in &self.plants {
+        // bottom layer first, so shells go on top of the plants they protect
+        let mut plants: Vec<_> = self.plants.iter().collect();
+        plants.sort_by_key(|p| p.layer());
+        for plant in plants {
             plant.draw();
+            if plant.is_sleeping() {
+                draw_text("z z", plant.x() + 8.0, plant.y() - 24.0, 22.0, DARKGRAY);
+            }
         }
         for proj in &self.projectiles {
             proj.draw();
@@ -218,5 +465,40 @@ impl Game {
         if let Some(tile) = self.grid.get_tile_at(mouse) {
             draw_rectangle_lines(tile.x, tile.y, TILE_SIZE, TILE_SIZE, 4.0, YELLOW);
         }
+
+        if let Some((text, _)) = &self.banner {
+            let metrics = measure_text(text, None, 40, 1.0);
+            draw_text(
+                text,
+                SCREEN_WIDTH / 2.0 - metrics.width / 2.0,
+                UI_BAR_HEIGHT + ROWS as f32 * TILE_SIZE / 2.0,
+                40.0,
+                RED,
+            );
+        }
+
+        if self.mode == GameMode::Survival {
+            draw_text(
+                &format!("Round {}", self.round),
+                SCREEN_WIDTH - 120.0,
+                UI_BAR_HEIGHT + ROWS as f32 * TILE_SIZE + 40.0,
+                28.0,
+                BLACK,
+            );
+        }
+
+        self.toasts.draw();
+    }
+}
+
+impl EventListener for Game {
+    fn on_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::LaneBreached { .. } => self.lost = true,
+            GameEvent::WaveStarted { flag: true, .. } => {
+                self.banner = Some(("A huge wave of zombies is approaching!".to_string(), 3.0));
+            }
+            _ => {}
+        }
     }
 }
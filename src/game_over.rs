@@ -1,12 +1,52 @@
+use crate::achievement::Achievement;
+use crate::factory::{plant_factory::PlantType, zombie_factory::ZombieType};
+use crate::game_state::GameMode;
+use crate::profile::Profile;
+use crate::stats::MatchStats;
 use macroquad::prelude::*;
+use strum::IntoEnumIterator;
 
 pub struct GameOver {
     pub restart: bool,
+    pub stats: MatchStats,
+    pub lifetime: MatchStats,
+    pub games_played: u32,
+    pub new_achievements: Vec<&'static Achievement>,
+    pub mode: GameMode,
+    pub survival_scores: Vec<u32>,
+    pub new_best: bool,
+    pub unlocked_plant: Option<PlantType>,
 }
 
 impl GameOver {
-    pub fn new() -> Self {
-        Self { restart: false }
+    pub fn new(
+        stats: MatchStats,
+        new_achievements: Vec<&'static Achievement>,
+        mode: GameMode,
+        new_best: bool,
+        unlocked_plant: Option<PlantType>,
+        profile: &Profile,
+    ) -> Self {
+        Self {
+            restart: false,
+            stats,
+            lifetime: profile.lifetime.clone(),
+            games_played: profile.games_played,
+            new_achievements,
+            mode,
+            survival_scores: profile.survival_scores.clone(),
+            new_best,
+            unlocked_plant,
+        }
+    }
+
+    fn restart_button() -> Rect {
+        Rect::new(
+            screen_width() / 2.0 - 80.0,
+            screen_height() - 150.0,
+            160.0,
+            50.0,
+        )
     }
 
     pub fn update(&mut self) {
@@ -16,13 +56,8 @@ impl GameOver {
         }
 
         if is_mouse_button_pressed(MouseButton::Left) {
-            let (mx, my) = mouse_position();
-            let btn_x = screen_width() / 2.0 - 80.0;
-            let btn_y = screen_height() / 2.0 + 40.0;
-            let btn_w = 160.0;
-            let btn_h = 50.0;
-
-            if mx >= btn_x && mx <= btn_x + btn_w && my >= btn_y && my <= btn_y + btn_h {
+            let mouse: Vec2 = mouse_position().into();
+            if Self::restart_button().contains(mouse) {
                 self.restart = true;
             }
         }
@@ -32,37 +67,161 @@ impl GameOver {
         clear_background(DARKGRAY);
 
         // Title
-        let text = "GAME OVER!";
+        let (text, color) = if self.stats.won {
+            ("LEVEL COMPLETE!", GREEN)
+        } else {
+            ("GAME OVER!", RED)
+        };
         let metrics = measure_text(text, None, 60, 1.0);
         draw_text(
             text,
             screen_width() / 2.0 - metrics.width / 2.0,
-            screen_height() / 2.0 - 40.0,
+            80.0,
             60.0,
-            RED,
+            color,
+        );
+
+        if let Some(plant) = self.unlocked_plant {
+            let text = format!("New plant unlocked: {:?}!", plant);
+            let metrics = measure_text(&text, None, 30, 1.0);
+            draw_text(
+                &text,
+                screen_width() / 2.0 - metrics.width / 2.0,
+                screen_height() - 210.0,
+                30.0,
+                GOLD,
+            );
+        }
+
+        let summary = format!(
+            "Survived {}   Sun produced {}   Sun collected {}",
+            format_time(self.stats.time_survived),
+            self.stats.sun_produced,
+            self.stats.sun_collected,
+        );
+        let metrics = measure_text(&summary, None, 24, 1.0);
+        draw_text(
+            &summary,
+            screen_width() / 2.0 - metrics.width / 2.0,
+            125.0,
+            24.0,
+            WHITE,
+        );
+
+        // Kills by zombie type
+        let left_x = 60.0;
+        let mut y = 180.0;
+        draw_text(
+            &format!("Zombies killed: {}", self.stats.total_kills()),
+            left_x,
+            y,
+            26.0,
+            YELLOW,
+        );
+        for zombie in ZombieType::iter() {
+            let kills = self.stats.kills.get(&zombie).copied().unwrap_or(0);
+            if kills == 0 {
+                continue;
+            }
+            y += 26.0;
+            draw_text(&format!("{:?}", zombie), left_x, y, 22.0, WHITE);
+            draw_text(&format!("{}", kills), left_x + 180.0, y, 22.0, WHITE);
+        }
+
+        // Survival rounds and high scores
+        if self.mode == GameMode::Survival {
+            y += 50.0;
+            let rounds = if self.new_best {
+                format!("Rounds: {} NEW BEST!", self.stats.rounds)
+            } else {
+                format!("Rounds: {}", self.stats.rounds)
+            };
+            draw_text(&rounds, left_x, y, 26.0, GOLD);
+            y += 30.0;
+            draw_text("Best rounds", left_x, y, 26.0, YELLOW);
+            for (i, best) in self.survival_scores.iter().enumerate() {
+                y += 26.0;
+                draw_text(&format!("{}. {}", i + 1, best), left_x, y, 22.0, WHITE);
+            }
+        }
+
+        // Per-plant table
+        let table_x = 340.0;
+        let mut y = 180.0;
+        draw_text("Plant", table_x, y, 26.0, YELLOW);
+        draw_text("Placed", table_x + 200.0, y, 26.0, YELLOW);
+        draw_text("Lost", table_x + 290.0, y, 26.0, YELLOW);
+        draw_text("Damage", table_x + 360.0, y, 26.0, YELLOW);
+        for plant in PlantType::iter() {
+            let placed = self.stats.plants_placed.get(&plant).copied().unwrap_or(0);
+            let lost = self.stats.plants_lost.get(&plant).copied().unwrap_or(0);
+            let damage = self.stats.damage_dealt.get(&plant).copied().unwrap_or(0);
+            if placed == 0 && lost == 0 && damage == 0 {
+                continue;
+            }
+            y += 26.0;
+            draw_text(&format!("{:?}", plant), table_x, y, 22.0, WHITE);
+            draw_text(&format!("{}", placed), table_x + 200.0, y, 22.0, WHITE);
+            draw_text(&format!("{}", lost), table_x + 290.0, y, 22.0, WHITE);
+            draw_text(&format!("{}", damage), table_x + 360.0, y, 22.0, WHITE);
+        }
+
+        // Achievements unlocked this match
+        let mut y = screen_height() - 250.0;
+        if self.unlocked_plant.is_some() {
+            y -= 40.0;
+        }
+        for achievement in &self.new_achievements {
+            let text = format!("Achievement unlocked: {}", achievement.name);
+            let metrics = measure_text(&text, None, 22, 1.0);
+            draw_text(
+                &text,
+                screen_width() / 2.0 - metrics.width / 2.0,
+                y,
+                22.0,
+                GOLD,
+            );
+            y -= 24.0;
+        }
+
+        // Lifetime totals
+        let lifetime = format!(
+            "Lifetime: {} games   {} kills   {} survived",
+            self.games_played,
+            self.lifetime.total_kills(),
+            format_time(self.lifetime.time_survived),
+        );
+        let metrics = measure_text(&lifetime, None, 22, 1.0);
+        draw_text(
+            &lifetime,
+            screen_width() / 2.0 - metrics.width / 2.0,
+            screen_height() - 180.0,
+            22.0,
+            LIGHTGRAY,
         );
 
         // Button
-        let btn_x = screen_width() / 2.0 - 80.0;
-        let btn_y = screen_height() / 2.0 + 40.0;
-        let btn_w = 160.0;
-        let btn_h = 50.0;
+        let btn = Self::restart_button();
 
-        draw_rectangle(btn_x, btn_y, btn_w, btn_h, LIGHTGRAY);
-        draw_rectangle_lines(btn_x, btn_y, btn_w, btn_h, 3.0, BLACK);
+        draw_rectangle(btn.x, btn.y, btn.w, btn.h, LIGHTGRAY);
+        draw_rectangle_lines(btn.x, btn.y, btn.w, btn.h, 3.0, BLACK);
 
-        let btn_text = "Restart";
+        let btn_text = if self.stats.won {
+            "Continue"
+        } else {
+            "Restart"
+        };
         let metrics = measure_text(btn_text, None, 30, 1.0);
         draw_text(
             btn_text,
-            btn_x + (btn_w - metrics.width) / 2.0,
-            btn_y + btn_h / 2.0 + metrics.height / 2.0,
+            btn.x + (btn.w - metrics.width) / 2.0,
+            btn.y + btn.h / 2.0 + metrics.height / 2.0,
             30.0,
             BLACK,
         );
 
         draw_text(
-            "Press ENTER to Restart",
+            &format!("Press ENTER to {}", btn_text),
             20.0,
             screen_height() - 40.0,
             25.0,
@@ -70,3 +229,8 @@ impl GameOver {
         );
     }
 }
+
+fn format_time(seconds: f32) -> String {
+    let total = seconds as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
@@ -1,5 +1,15 @@
 pub enum GameState {
+    MainMenu,
     PlantSelect,
     Playing,
     GameOver,
+    Achievements,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameMode {
+    QuickPlay,
+    Survival,
+    /// Index into `campaign::LEVELS`
+    Adventure(usize),
 }
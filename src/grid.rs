@@ -7,6 +7,14 @@ pub struct Tile {
     pub col: usize,
     pub x: f32,
     pub y: f32,
+    /// Seconds until the tile can be planted on again, e.g. after a crater
+    pub blocked_timer: f32,
+}
+
+impl Tile {
+    pub fn is_blocked(&self) -> bool {
+        self.blocked_timer > 0.0
+    }
 }
 
 pub struct Grid {
@@ -20,14 +28,42 @@ impl Grid {
             for col in 0..COLS {
                 let x = col as f32 * TILE_SIZE;
                 let y = row as f32 * TILE_SIZE + UI_BAR_HEIGHT;
-                tiles.push(Tile { row, col, x, y });
+                tiles.push(Tile {
+                    row,
+                    col,
+                    x,
+                    y,
+                    blocked_timer: 0.0,
+                });
             }
         }
         Grid { tiles }
     }
 
+    pub fn update(&mut self, dt: f32) {
+        for tile in &mut self.tiles {
+            tile.blocked_timer = (tile.blocked_timer - dt).max(0.0);
+        }
+    }
+
+    /// Makes the tile under `pos` unplantable for `duration` seconds
+    pub fn block_tile_at(&mut self, pos: Vec2, duration: f32) {
+        if let Some(tile) = self.tiles.iter_mut().find(|t| {
+            pos.x >= t.x && pos.x < t.x + TILE_SIZE && pos.y >= t.y && pos.y < t.y + TILE_SIZE
+        }) {
+            tile.blocked_timer = tile.blocked_timer.max(duration);
+        }
+    }
+
     pub fn draw(&self) {
         for tile in &self.tiles {
+            if tile.is_blocked() {
+                // crater
+                let cx = tile.x + TILE_SIZE / 2.0;
+                let cy = tile.y + TILE_SIZE / 2.0;
+                draw_ellipse(cx, cy, 40.0, 28.0, 0.0, DARKBROWN);
+                draw_ellipse(cx, cy + 4.0, 30.0, 18.0, 0.0, BLACK);
+            }
             draw_rectangle_lines(tile.x, tile.y, TILE_SIZE, TILE_SIZE, 2.0, DARKGREEN);
         }
     }
@@ -45,3 +81,13 @@ impl Grid {
         None
     }
 }
+
+/// World-space y of the middle of a lane
+pub fn row_center_y(row: usize) -> f32 {
+    row as f32 * TILE_SIZE + TILE_SIZE / 2.0 + UI_BAR_HEIGHT
+}
+
+/// The lane itself plus the lanes directly above and below it, if on the lawn
+pub fn lanes_around(row: usize) -> Vec<usize> {
+    (row.saturating_sub(1)..=(row + 1).min(ROWS - 1)).collect()
+}
@@ -1,23 +1,53 @@
-use crate::constants::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::achievements_screen::AchievementsScreen;
+use crate::campaign::{LEVELS, unlocked_plants};
+use crate::constants::{MAX_SELECTED_PLANTS, SCREEN_HEIGHT, SCREEN_WIDTH};
 use crate::game::Game;
 use crate::game_over::GameOver;
-use crate::game_state::GameState;
+use crate::game_state::{GameMode, GameState};
+use crate::main_menu::{MainMenu, MenuChoice};
 use crate::plant_select::PlantSelect;
+use crate::profile::Profile;
+use crate::stats::MatchStats;
 use macroquad::prelude::*;
 
+mod achievement;
+mod achievements_screen;
+mod campaign;
 mod constants;
+mod event;
 mod factory;
 mod game;
 mod game_over;
 mod game_state;
 mod grid;
+mod main_menu;
 mod plant;
 mod plant_bar;
 mod plant_select;
+mod profile;
 mod projectile;
+mod spawner;
+mod stats;
 mod sun;
+mod toast;
 mod zombie;
 
+/// Seed selection screen for starting a fresh match in `mode`
+fn plant_select_for(mode: GameMode, profile: &Profile) -> PlantSelect {
+    match mode {
+        GameMode::Adventure(level) => PlantSelect::for_level(level, profile.campaign_progress),
+        _ => PlantSelect::new(
+            unlocked_plants(profile.campaign_progress),
+            MAX_SELECTED_PLANTS,
+        ),
+    }
+}
+
+/// The next Adventure level to play; the last one is replayed once all are done
+fn next_level(profile: &Profile) -> usize {
+    profile.campaign_progress.min(LEVELS.len() - 1)
+}
+
 fn window_conf() -> Conf {
     Conf {
         window_title: "PVZ Rust".to_string(),
@@ -31,29 +61,105 @@ fn window_conf() -> Conf {
 
 #[macroquad::main(window_conf)]
 async fn main() {
-    let mut state = GameState::PlantSelect;
-    let mut plant_select = PlantSelect::new();
+    let mut state = GameState::MainMenu;
+    let mut main_menu = MainMenu::new();
+    let mut mode = GameMode::QuickPlay;
     let mut game: Option<Game> = None;
-    let mut game_over = GameOver::new();
+    let mut profile = Profile::load();
+    let mut plant_select = plant_select_for(mode, &profile);
+    let mut game_over = GameOver::new(
+        MatchStats::new(),
+        Vec::new(),
+        GameMode::QuickPlay,
+        false,
+        None,
+        &profile,
+    );
+    let mut achievements_screen = AchievementsScreen::new();
 
     loop {
         match &mut state {
+            GameState::MainMenu => {
+                main_menu.update();
+                main_menu.draw(&profile);
+
+                match main_menu.choice.take() {
+                    Some(MenuChoice::Adventure) => {
+                        mode = GameMode::Adventure(next_level(&profile));
+                        plant_select = plant_select_for(mode, &profile);
+                        state = GameState::PlantSelect;
+                    }
+                    Some(MenuChoice::QuickPlay) => {
+                        mode = GameMode::QuickPlay;
+                        plant_select = plant_select_for(mode, &profile);
+                        state = GameState::PlantSelect;
+                    }
+                    Some(MenuChoice::Survival) => {
+                        mode = GameMode::Survival;
+                        plant_select = plant_select_for(mode, &profile);
+                        state = GameState::PlantSelect;
+                    }
+                    Some(MenuChoice::Achievements) => {
+                        achievements_screen = AchievementsScreen::new();
+                        state = GameState::Achievements;
+                    }
+                    None => {}
+                }
+            }
             GameState::PlantSelect => {
                 if let Some(selected_plants) = plant_select.update() {
-                    game = Some(Game::new(selected_plants));
+                    match &mut game {
+                        // between Survival rounds the lawn carries over
+                        Some(g) => g.next_round(selected_plants),
+                        None => game = Some(Game::new(selected_plants, mode, &profile)),
+                    }
                     state = GameState::Playing;
                 }
                 plant_select.draw();
+
+                if plant_select.back && game.is_none() {
+                    main_menu = MainMenu::new();
+                    state = GameState::MainMenu;
+                }
             }
             GameState::Playing => {
                 if let Some(g) = &mut game {
                     g.update();
                     g.draw();
 
-                    if g.is_lost() {
+                    if g.is_lost() || g.is_won() {
+                        g.stats.won = g.is_won();
+                        g.achievements.finish_match(&g.stats, g.stats.won);
+                        profile.record_match(&g.stats);
+                        profile.achievements = g.achievements.unlocked.clone();
+                        let new_best =
+                            mode == GameMode::Survival && profile.record_survival(g.stats.rounds);
+                        let mut unlocked_plant = None;
+                        if let GameMode::Adventure(level) = mode
+                            && g.stats.won
+                            && profile.complete_level(level)
+                        {
+                            unlocked_plant = LEVELS[level].reward;
+                        }
+                        profile.save();
+                        game_over = GameOver::new(
+                            g.stats.clone(),
+                            g.achievements.newly_unlocked.clone(),
+                            mode,
+                            new_best,
+                            unlocked_plant,
+                            &profile,
+                        );
                         state = GameState::GameOver;
                         game = None;
-                        game_over = GameOver::new();
+                    } else if g.round_cleared {
+                        let previous = g.plant_bar.slots.iter().map(|s| s.plant).collect();
+                        plant_select = PlantSelect::between_rounds(
+                            g.round,
+                            previous,
+                            unlocked_plants(profile.campaign_progress),
+                        );
+                        state = GameState::PlantSelect;
                     }
                 }
             }
@@ -62,10 +168,22 @@ async fn main() {
                 game_over.draw();
 
                 if game_over.restart {
-                    plant_select = PlantSelect::new();
+                    if let GameMode::Adventure(_) = mode {
+                        mode = GameMode::Adventure(next_level(&profile));
+                    }
+                    plant_select = plant_select_for(mode, &profile);
                     state = GameState::PlantSelect;
                 }
             }
+            GameState::Achievements => {
+                achievements_screen.update();
+                achievements_screen.draw(&profile);
+
+                if achievements_screen.back {
+                    main_menu = MainMenu::new();
+                    state = GameState::MainMenu;
+                }
+            }
         }
         next_frame().await;
     }
@@ -0,0 +1,120 @@
+use crate::campaign::LEVELS;
+use crate::constants::*;
+use crate::profile::Profile;
+use macroquad::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MenuChoice {
+    Adventure,
+    QuickPlay,
+    Survival,
+    Achievements,
+}
+
+const BUTTONS: &[(MenuChoice, &str)] = &[
+    (MenuChoice::Adventure, "Adventure"),
+    (MenuChoice::QuickPlay, "Quick Play"),
+    (MenuChoice::Survival, "Survival: Endless"),
+    (MenuChoice::Achievements, "Achievements"),
+];
+
+pub struct MainMenu {
+    pub choice: Option<MenuChoice>,
+}
+
+impl MainMenu {
+    pub fn new() -> Self {
+        Self { choice: None }
+    }
+
+    fn button_rect(i: usize) -> Rect {
+        Rect::new(120.0, 200.0 + i as f32 * 80.0, 300.0, 60.0)
+    }
+
+    pub fn update(&mut self) {
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let mouse: Vec2 = mouse_position().into();
+            for (i, (choice, _)) in BUTTONS.iter().enumerate() {
+                if Self::button_rect(i).contains(mouse) {
+                    self.choice = Some(*choice);
+                }
+            }
+        }
+    }
+
+    pub fn draw(&self, profile: &Profile) {
+        clear_background(LIGHTGRAY);
+
+        draw_text("PVZ Rust", 120.0, 120.0, 70.0, DARKGREEN);
+
+        let mouse: Vec2 = mouse_position().into();
+        for (i, (_, label)) in BUTTONS.iter().enumerate() {
+            let btn = Self::button_rect(i);
+            let hovered = btn.contains(mouse);
+
+            draw_rectangle(
+                btn.x,
+                btn.y,
+                btn.w,
+                btn.h,
+                if hovered { WHITE } else { GRAY },
+            );
+            draw_rectangle_lines(btn.x, btn.y, btn.w, btn.h, 3.0, BLACK);
+
+            let metrics = measure_text(label, None, 30, 1.0);
+            draw_text(
+                label,
+                btn.x + (btn.w - metrics.width) / 2.0,
+                btn.y + btn.h / 2.0 + metrics.height / 2.0,
+                30.0,
+                BLACK,
+            );
+        }
+
+        draw_text(
+            &format!(
+                "Adventure: {}/{} levels complete",
+                profile.campaign_progress.min(LEVELS.len()),
+                LEVELS.len()
+            ),
+            120.0,
+            170.0,
+            24.0,
+            DARKGRAY,
+        );
+
+        // Survival high scores
+        let table_x = SCREEN_WIDTH - 360.0;
+        let table_y = 200.0;
+        draw_rectangle(table_x, table_y, 260.0, 240.0, WHITE);
+        draw_rectangle_lines(table_x, table_y, 260.0, 240.0, 3.0, BLACK);
+        draw_text(
+            "Survival Best Rounds",
+            table_x + 14.0,
+            table_y + 34.0,
+            26.0,
+            DARKGREEN,
+        );
+
+        if profile.survival_scores.is_empty() {
+            draw_text(
+                "No runs yet",
+                table_x + 14.0,
+                table_y + 74.0,
+                22.0,
+                DARKGRAY,
+            );
+        }
+        for (i, rounds) in profile.survival_scores.iter().enumerate() {
+            let y = table_y + 74.0 + i as f32 * 32.0;
+            draw_text(&format!("{}.", i + 1), table_x + 14.0, y, 24.0, BLACK);
+            draw_text(
+                &format!("{} rounds", rounds),
+                table_x + 60.0,
+                y,
+                24.0,
+                BLACK,
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Blover {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub timer: f32,
+    pub health: i32,
+}
+
+impl Blover {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            timer: 1.0,
+            health: 100,
+        }
+    }
+}
+
+impl Plant for Blover {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Blover
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        if self.timer <= 0.0 {
+            self.health = 0;
+            return vec![PlantAction::BlowAway];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // spins up faster until the gust
+        let angle = (1.0 - self.timer) * 1440.0;
+        draw_rectangle(self.x - 2.0, self.y, 4.0, 20.0, DARKGREEN);
+        for i in 0..4 {
+            let a = (angle + i as f32 * 90.0).to_radians();
+            let leaf = vec2(self.x, self.y) + Vec2::from_angle(a) * 10.0;
+            draw_circle(leaf.x, leaf.y, 8.0, GREEN);
+        }
+        draw_circle(self.x, self.y, 4.0, DARKGREEN);
+    }
+}
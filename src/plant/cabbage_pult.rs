@@ -0,0 +1,111 @@
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::lobbed::LobKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct CabbagePult {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub is_attacking: bool,
+}
+
+impl CabbagePult {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 3.0,
+            timer: 0.0,
+            health: 100,
+            attack_range: 800.0, // basically whole row
+            is_attacking: false,
+        }
+    }
+
+    fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        for z in zombies {
+            if z.row() == self.row
+                && z.x() > self.x
+                && z.x() - self.x <= self.attack_range
+                && !z.is_dead()
+                && z.is_grounded()
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Plant for CabbagePult {
+    fn plant_type(&self) -> PlantType {
+        PlantType::CabbagePult
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        let has_target = self.has_target(zombies);
+        self.is_attacking = has_target;
+
+        if has_target && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Lobbed(LobKind::Cabbage),
+                x: self.x,
+                y: self.y - 20.0,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // Body
+        draw_circle(self.x, self.y + 5.0, 18.0, LIME);
+        // Catapult arm with a cabbage loaded
+        draw_line(
+            self.x,
+            self.y - 5.0,
+            self.x - 18.0,
+            self.y - 22.0,
+            3.0,
+            DARKGREEN,
+        );
+        draw_circle(self.x - 20.0, self.y - 26.0, 7.0, GREEN);
+
+        // Eyes
+        draw_circle(self.x - 6.0, self.y, 2.0, BLACK);
+        draw_circle(self.x + 6.0, self.y, 2.0, BLACK);
+    }
+}
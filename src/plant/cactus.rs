@@ -0,0 +1,124 @@
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Cactus {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub is_attacking: bool,
+    // stretched up to reach a zombie in the air
+    pub extended: bool,
+}
+
+impl Cactus {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: 800.0, // basically whole row
+            is_attacking: false,
+            extended: false,
+        }
+    }
+
+    fn in_range(&self, z: &dyn Zombie) -> bool {
+        z.row() == self.row && z.x() > self.x && z.x() - self.x <= self.attack_range && !z.is_dead()
+    }
+}
+
+impl Plant for Cactus {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Cactus
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        let has_target = zombies.iter().any(|z| self.in_range(z.as_ref()));
+        let flying_target = zombies
+            .iter()
+            .any(|z| self.in_range(z.as_ref()) && !z.is_grounded());
+        self.is_attacking = has_target;
+        self.extended = flying_target;
+
+        if self.is_attacking && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Pea(PeaKind::Spike),
+                x: self.x + 25.0,
+                y: self.y,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        let height = if self.extended { 60.0 } else { 36.0 };
+        let top = self.y + 18.0 - height;
+
+        // Stem
+        draw_rectangle(self.x - 10.0, top, 20.0, height, GREEN);
+        draw_circle(self.x, top, 10.0, GREEN);
+        // Arms
+        draw_rectangle(self.x - 20.0, self.y - 4.0, 10.0, 6.0, GREEN);
+        draw_rectangle(self.x + 10.0, self.y - 10.0, 10.0, 6.0, GREEN);
+        // Spikes
+        for dy in [0.0, 12.0, 24.0] {
+            draw_line(
+                self.x + 10.0,
+                top + dy,
+                self.x + 15.0,
+                top + dy - 3.0,
+                1.5,
+                DARKGREEN,
+            );
+            draw_line(
+                self.x - 10.0,
+                top + dy,
+                self.x - 15.0,
+                top + dy - 3.0,
+                1.5,
+                DARKGREEN,
+            );
+        }
+
+        // Eyes
+        draw_circle(self.x - 4.0, top + 2.0, 2.0, BLACK);
+        draw_circle(self.x + 4.0, top + 2.0, 2.0, BLACK);
+    }
+}
@@ -1,22 +1,25 @@
 use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
 use crate::factory::projectile_factory::ProjectileKind;
 use crate::plant::plant::{Plant, PlantAction};
-use crate::projectile::projectile::Instakill;
+use crate::projectile::projectile::{BlastShape, Direction, Instakill};
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
 
 pub struct CherryBomb {
     pub x: f32,
     pub y: f32,
+    pub row: usize,
     pub timer: f32,
     pub health: i32,
 }
 
 impl CherryBomb {
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
         Self {
             x,
             y,
+            row,
             timer: 1.5,
             health: 100,
         }
@@ -24,12 +27,18 @@ impl CherryBomb {
 }
 
 impl Plant for CherryBomb {
+    fn plant_type(&self) -> PlantType {
+        PlantType::CherryBomb
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.health
     }
@@ -42,21 +51,26 @@ impl Plant for CherryBomb {
         false
     }
 
-    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Option<PlantAction> {
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
         self.timer -= dt;
 
         if self.timer <= 0.0 {
             self.health = 0;
-            return Some(PlantAction::Shoot {
+            return vec![PlantAction::Shoot {
                 kind: ProjectileKind::Instakill {
-                    radius: TILE_SIZE * 1.8,
+                    shape: BlastShape::Circle {
+                        radius: TILE_SIZE * 1.8,
+                    },
                     tier: Instakill::Medium,
                 },
                 x: self.x,
                 y: self.y,
-            });
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
         }
-        None
+        Vec::new()
     }
 
     fn draw(&self) {
@@ -81,8 +95,18 @@ impl Plant for CherryBomb {
         draw_circle(right_x, y, radius, RED);
 
         // Cherry shine highlight
-        draw_circle(left_x - 3.0 * scale, y - 5.0 * scale, 4.0 * scale * 0.3, PINK);
-        draw_circle(right_x - 3.0 * scale, y - 5.0 * scale, 4.0 * scale * 0.3, PINK);
+        draw_circle(
+            left_x - 3.0 * scale,
+            y - 5.0 * scale,
+            4.0 * scale * 0.3,
+            PINK,
+        );
+        draw_circle(
+            right_x - 3.0 * scale,
+            y - 5.0 * scale,
+            4.0 * scale * 0.3,
+            PINK,
+        );
 
         // Green stem
         draw_line(
@@ -0,0 +1,117 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Chomper {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    pub attack_range: f32,
+    pub chew_time: f32,
+    // seconds left chewing; can't bite again until it reaches zero
+    pub chew_timer: f32,
+}
+
+impl Chomper {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 100,
+            attack_range: TILE_SIZE,
+            chew_time: 30.0,
+            chew_timer: 0.0,
+        }
+    }
+
+    /// Index of the closest swallowable zombie within reach
+    fn find_target(&self, zombies: &[Box<dyn Zombie>]) -> Option<usize> {
+        zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| {
+                z.row() == self.row
+                    && z.x() >= self.x
+                    && z.x() - self.x <= self.attack_range
+                    && !z.is_dead()
+                    && z.is_chewable()
+            })
+            .min_by(|(_, a), (_, b)| a.x().total_cmp(&b.x()))
+            .map(|(i, _)| i)
+    }
+}
+
+impl Plant for Chomper {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Chomper
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        if self.chew_timer > 0.0 {
+            self.chew_timer -= dt;
+            return Vec::new();
+        }
+
+        if let Some(target) = self.find_target(zombies) {
+            self.chew_timer = self.chew_time;
+            return vec![PlantAction::Devour { target }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // Stem
+        draw_rectangle(self.x - 3.0, self.y, 6.0, 25.0, DARKGREEN);
+        // Head
+        draw_circle(self.x, self.y - 5.0, 22.0, PURPLE);
+
+        if self.chew_timer > 0.0 {
+            // mouth shut, cheeks bulging
+            draw_line(
+                self.x,
+                self.y - 5.0,
+                self.x + 22.0,
+                self.y - 5.0,
+                3.0,
+                BLACK,
+            );
+            draw_circle(self.x - 12.0, self.y + 2.0, 6.0, VIOLET);
+        } else {
+            // open jaws with teeth
+            draw_triangle(
+                vec2(self.x + 2.0, self.y - 5.0),
+                vec2(self.x + 24.0, self.y - 20.0),
+                vec2(self.x + 24.0, self.y + 10.0),
+                MAROON,
+            );
+            draw_circle(self.x + 16.0, self.y - 13.0, 2.0, WHITE);
+            draw_circle(self.x + 16.0, self.y + 3.0, 2.0, WHITE);
+        }
+
+        // Eye
+        draw_circle(self.x - 6.0, self.y - 14.0, 2.5, BLACK);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction, PlantEffect, PlantLayer};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+/// Dropped onto a sleeping mushroom; wakes it up and is used up
+pub struct CoffeeBean {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub timer: f32,
+    pub health: i32,
+}
+
+impl CoffeeBean {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            timer: 1.0,
+            health: 100,
+        }
+    }
+}
+
+impl Plant for CoffeeBean {
+    fn plant_type(&self) -> PlantType {
+        PlantType::CoffeeBean
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn layer(&self) -> PlantLayer {
+        PlantLayer::Top
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        if self.timer <= 0.0 {
+            self.health = 0;
+            return vec![PlantAction::AffectOccupant {
+                x: self.x,
+                y: self.y,
+                effect: PlantEffect::Wake,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // bobs above the mushroom until it's drunk
+        let bob = (self.timer * 12.0).sin() * 3.0;
+        let y = self.y - 30.0 + bob;
+        draw_ellipse(self.x, y, 9.0, 12.0, 0.0, DARKBROWN);
+        draw_line(self.x, y - 9.0, self.x, y + 9.0, 2.0, BROWN);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::projectile::{BlastShape, Direction, Instakill};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct DoomShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub timer: f32,
+    pub health: i32,
+    pub crater_time: f32,
+    pub sleeping: bool,
+}
+
+impl DoomShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            timer: 1.0,
+            health: 100,
+            crater_time: 60.0,
+            sleeping: false,
+        }
+    }
+}
+
+impl Plant for DoomShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::DoomShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        if self.timer <= 0.0 {
+            self.health = 0;
+            return vec![
+                PlantAction::Shoot {
+                    kind: ProjectileKind::Instakill {
+                        shape: BlastShape::Circle {
+                            radius: TILE_SIZE * 3.5,
+                        },
+                        tier: Instakill::High,
+                    },
+                    x: self.x,
+                    y: self.y,
+                    row: self.row,
+                    direction: Direction::Forward,
+                    delay: 0.0,
+                },
+                PlantAction::Crater {
+                    x: self.x,
+                    y: self.y,
+                    duration: self.crater_time,
+                },
+            ];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // swells up before it goes off
+        let swell = if self.sleeping {
+            1.0
+        } else {
+            1.0 + 0.4 * (1.0 - self.timer).clamp(0.0, 1.0)
+        };
+
+        draw_rectangle(self.x - 6.0, self.y, 12.0, 16.0, GRAY);
+        draw_ellipse(
+            self.x,
+            self.y - 2.0,
+            20.0 * swell,
+            14.0 * swell,
+            0.0,
+            DARKPURPLE,
+        );
+        draw_circle(self.x - 7.0, self.y - 7.0, 3.0, PURPLE);
+        draw_circle(self.x + 8.0, self.y - 5.0, 2.5, PURPLE);
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { RED };
+        draw_circle(self.x - 4.0, self.y + 6.0, 1.8, eye);
+        draw_circle(self.x + 4.0, self.y + 6.0, 1.8, eye);
+    }
+}
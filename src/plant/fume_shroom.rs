@@ -0,0 +1,128 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const FUME_SHOW_TIME: f32 = 0.4;
+
+pub struct FumeShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub damage: i32,
+    pub sleeping: bool,
+    // counts down while the fume cloud is drawn
+    pub fume_timer: f32,
+}
+
+impl FumeShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: TILE_SIZE * 4.0,
+            damage: 20,
+            sleeping: false,
+            fume_timer: 0.0,
+        }
+    }
+
+    fn in_range(&self, z: &dyn Zombie) -> bool {
+        z.row() == self.row
+            && z.x() > self.x
+            && z.x() - self.x <= self.attack_range
+            && !z.is_dead()
+            && z.is_grounded()
+    }
+}
+
+impl Plant for FumeShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::FumeShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+        self.fume_timer -= dt;
+
+        if self.timer > 0.0 {
+            return Vec::new();
+        }
+
+        // the fumes go straight through every zombie in reach
+        let actions: Vec<PlantAction> = zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| self.in_range(z.as_ref()))
+            .map(|(target, _)| PlantAction::Damage {
+                target,
+                amount: self.damage,
+            })
+            .collect();
+        if !actions.is_empty() {
+            self.timer = self.cooldown;
+            self.fume_timer = FUME_SHOW_TIME;
+        }
+        actions
+    }
+
+    fn draw(&self) {
+        if self.fume_timer > 0.0 {
+            let alpha = self.fume_timer / FUME_SHOW_TIME * 0.6;
+            draw_rectangle(
+                self.x + 20.0,
+                self.y - 12.0,
+                self.attack_range - 20.0,
+                24.0,
+                Color::new(0.6, 0.3, 0.8, alpha),
+            );
+        }
+
+        draw_rectangle(self.x - 8.0, self.y, 16.0, 18.0, BEIGE);
+        draw_ellipse(self.x, self.y - 2.0, 22.0, 15.0, 0.0, PURPLE);
+        // nozzle
+        draw_rectangle(self.x + 12.0, self.y - 6.0, 12.0, 10.0, DARKPURPLE);
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 4.0, self.y + 6.0, 2.0, eye);
+        draw_circle(self.x + 4.0, self.y + 6.0, 2.0, eye);
+    }
+}
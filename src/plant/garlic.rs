@@ -0,0 +1,75 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Garlic {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+}
+
+impl Garlic {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 200,
+        }
+    }
+}
+
+impl Plant for Garlic {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Garlic
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    // one bite is enough to send a zombie elsewhere
+    fn diverts_zombies(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, _dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        draw_circle(self.x, self.y + 4.0, 18.0, WHITE);
+        draw_circle(self.x, self.y + 4.0, 15.0, Color::new(0.95, 0.9, 0.95, 1.0));
+        draw_triangle(
+            vec2(self.x - 6.0, self.y - 10.0),
+            vec2(self.x + 6.0, self.y - 10.0),
+            vec2(self.x, self.y - 22.0),
+            WHITE,
+        );
+
+        // bite marks once it's been chewed on
+        if self.health < 140 {
+            draw_circle(self.x + 16.0, self.y, 4.0, DARKGREEN);
+        }
+        if self.health < 70 {
+            draw_circle(self.x + 14.0, self.y + 12.0, 4.0, DARKGREEN);
+        }
+    }
+}
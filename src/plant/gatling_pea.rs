@@ -0,0 +1,115 @@
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const PEA: PeaKind = PeaKind::Normal;
+
+pub struct GatlingPea {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub is_attacking: bool,
+    pub burst: usize,
+    pub burst_gap: f32,
+}
+
+impl GatlingPea {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: 800.0, // basically whole row
+            is_attacking: false,
+            burst: 4,
+            burst_gap: 0.12,
+        }
+    }
+
+    fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        for z in zombies {
+            if z.row() == self.row
+                && z.x() > self.x
+                && z.x() - self.x <= self.attack_range
+                && !z.is_dead()
+                && (z.is_grounded() || PEA.hits_air())
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Plant for GatlingPea {
+    fn plant_type(&self) -> PlantType {
+        PlantType::GatlingPea
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        let has_target = self.has_target(zombies);
+        self.is_attacking = has_target;
+
+        if has_target && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            // four-pea burst
+            return (0..self.burst)
+                .map(|i| PlantAction::Shoot {
+                    kind: ProjectileKind::Pea(PEA),
+                    x: self.x + 25.0,
+                    y: self.y,
+                    row: self.row,
+                    direction: Direction::Forward,
+                    delay: i as f32 * self.burst_gap,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // Body
+        draw_circle(self.x, self.y, 20.0, DARKGREEN);
+        // Head (pea cannon)
+        draw_circle(self.x + 20.0, self.y, 10.0, GRAY);
+        // Barrel rings and army helmet
+        draw_rectangle(self.x + 18.0, self.y - 6.0, 14.0, 12.0, DARKGRAY);
+        draw_ellipse(self.x, self.y - 16.0, 18.0, 8.0, 0.0, DARKGREEN);
+
+        // Eyes
+        draw_circle(self.x - 6.0, self.y - 5.0, 2.0, BLACK);
+        draw_circle(self.x + 6.0, self.y - 5.0, 2.0, BLACK);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const FUME_SHOW_TIME: f32 = 0.4;
+
+pub struct GloomShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub damage: i32,
+    pub sleeping: bool,
+    // counts down while the fume cloud is drawn
+    pub fume_timer: f32,
+}
+
+impl GloomShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: TILE_SIZE * 1.5,
+            damage: 20,
+            sleeping: false,
+            fume_timer: 0.0,
+        }
+    }
+
+    // the surrounding tiles, in every direction
+    fn in_range(&self, z: &dyn Zombie) -> bool {
+        !z.is_dead()
+            && z.is_grounded()
+            && (z.x() - self.x).abs() <= self.attack_range
+            && (z.y() - self.y).abs() <= self.attack_range
+    }
+}
+
+impl Plant for GloomShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::GloomShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+        self.fume_timer -= dt;
+
+        if self.timer > 0.0 {
+            return Vec::new();
+        }
+
+        let actions: Vec<PlantAction> = zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| self.in_range(z.as_ref()))
+            .map(|(target, _)| PlantAction::Damage {
+                target,
+                amount: self.damage,
+            })
+            .collect();
+        if !actions.is_empty() {
+            self.timer = self.cooldown;
+            self.fume_timer = FUME_SHOW_TIME;
+        }
+        actions
+    }
+
+    fn draw(&self) {
+        if self.fume_timer > 0.0 {
+            let alpha = self.fume_timer / FUME_SHOW_TIME * 0.5;
+            draw_rectangle(
+                self.x - self.attack_range,
+                self.y - self.attack_range,
+                self.attack_range * 2.0,
+                self.attack_range * 2.0,
+                Color::new(0.4, 0.2, 0.6, alpha),
+            );
+        }
+
+        draw_rectangle(self.x - 8.0, self.y, 16.0, 18.0, GRAY);
+        draw_ellipse(self.x, self.y - 2.0, 24.0, 16.0, 0.0, DARKPURPLE);
+        // nozzles all round
+        for dx in [-22.0, 16.0] {
+            draw_rectangle(self.x + dx, self.y - 6.0, 6.0, 8.0, PURPLE);
+        }
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 4.0, self.y + 6.0, 2.0, eye);
+        draw_circle(self.x + 4.0, self.y + 6.0, 2.0, eye);
+    }
+}
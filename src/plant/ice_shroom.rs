@@ -0,0 +1,100 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct IceShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub timer: f32,
+    pub health: i32,
+    pub freeze_time: f32,
+    pub chill_time: f32,
+    pub sleeping: bool,
+}
+
+impl IceShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            timer: 1.0,
+            health: 100,
+            freeze_time: 4.0,
+            chill_time: 10.0,
+            sleeping: false,
+        }
+    }
+}
+
+impl Plant for IceShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::IceShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        if self.timer <= 0.0 {
+            self.health = 0;
+            return vec![PlantAction::FreezeAll {
+                duration: self.freeze_time,
+                chill: self.chill_time,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // frosty glow builds up before it goes off
+        if !self.sleeping {
+            let glow = (1.0 - self.timer).clamp(0.0, 1.0);
+            draw_circle(
+                self.x,
+                self.y,
+                20.0 + 10.0 * glow,
+                Color::new(0.7, 0.9, 1.0, 0.5 * glow),
+            );
+        }
+
+        draw_rectangle(self.x - 6.0, self.y, 12.0, 16.0, BEIGE);
+        draw_ellipse(self.x, self.y - 2.0, 20.0, 14.0, 0.0, SKYBLUE);
+        draw_circle(self.x - 8.0, self.y - 6.0, 3.0, WHITE);
+        draw_circle(self.x + 7.0, self.y - 8.0, 2.0, WHITE);
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 4.0, self.y + 6.0, 1.8, eye);
+        draw_circle(self.x + 4.0, self.y + 6.0, 1.8, eye);
+    }
+}
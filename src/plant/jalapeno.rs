@@ -0,0 +1,91 @@
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::projectile::{BlastShape, Direction, Instakill};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Jalapeno {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub timer: f32,
+    pub health: i32,
+}
+
+impl Jalapeno {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            timer: 1.0,
+            health: 100,
+        }
+    }
+}
+
+impl Plant for Jalapeno {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Jalapeno
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        if self.timer <= 0.0 {
+            self.health = 0;
+            // burns the whole row
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Instakill {
+                    shape: BlastShape::Lane,
+                    tier: Instakill::Medium,
+                },
+                x: self.x,
+                y: self.y,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // swells and shakes before bursting
+        let progress = (1.0 - self.timer).clamp(0.0, 1.0);
+        let shake = (self.timer * 40.0).sin() * 2.0 * progress;
+        let x = self.x + shake;
+        let w = 12.0 + 4.0 * progress;
+
+        draw_ellipse(x, self.y + 4.0, w, 24.0, 0.0, RED);
+        // stem
+        draw_rectangle(x - 3.0, self.y - 26.0, 6.0, 8.0, DARKGREEN);
+
+        // angry eyes
+        draw_circle(x - 4.0, self.y - 6.0, 2.0, BLACK);
+        draw_circle(x + 4.0, self.y - 6.0, 2.0, BLACK);
+        draw_line(x - 8.0, self.y - 12.0, x - 2.0, self.y - 9.0, 2.0, BLACK);
+        draw_line(x + 8.0, self.y - 12.0, x + 2.0, self.y - 9.0, 2.0, BLACK);
+    }
+}
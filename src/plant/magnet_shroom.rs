@@ -0,0 +1,121 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::accessory::AccessoryKind;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct MagnetShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    pub attack_range: f32,
+    pub recharge_time: f32,
+    pub timer: f32,
+    // what it's stuck holding until it recharges
+    pub holding: Option<AccessoryKind>,
+    pub sleeping: bool,
+}
+
+impl MagnetShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 100,
+            attack_range: TILE_SIZE * 2.5,
+            recharge_time: 15.0,
+            timer: 0.0,
+            holding: None,
+            sleeping: false,
+        }
+    }
+
+    /// Index of the closest zombie in range wearing or carrying metal
+    fn find_target(&self, zombies: &[Box<dyn Zombie>]) -> Option<usize> {
+        let distance = |z: &dyn Zombie| vec2(z.x() - self.x, z.y() - self.y).length();
+        zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| {
+                !z.is_dead()
+                    && z.accessory().is_some_and(|a| a.is_metal())
+                    && distance(z.as_ref()) <= self.attack_range
+            })
+            .min_by(|(_, a), (_, b)| distance(a.as_ref()).total_cmp(&distance(b.as_ref())))
+            .map(|(i, _)| i)
+    }
+}
+
+impl Plant for MagnetShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::MagnetShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        if self.timer > 0.0 {
+            self.timer -= dt;
+            if self.timer <= 0.0 {
+                self.holding = None;
+            }
+            return Vec::new();
+        }
+
+        if let Some(target) = self.find_target(zombies) {
+            self.holding = zombies[target].accessory();
+            self.timer = self.recharge_time;
+            return vec![PlantAction::PullAccessory { target }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        draw_rectangle(self.x - 6.0, self.y, 12.0, 16.0, BEIGE);
+        draw_ellipse(self.x, self.y - 2.0, 20.0, 14.0, 0.0, PURPLE);
+
+        // horseshoe magnet on the cap
+        let magnet = if self.holding.is_some() { GRAY } else { RED };
+        draw_rectangle(self.x - 10.0, self.y - 22.0, 5.0, 14.0, magnet);
+        draw_rectangle(self.x + 5.0, self.y - 22.0, 5.0, 14.0, magnet);
+        draw_rectangle(self.x - 10.0, self.y - 10.0, 20.0, 4.0, magnet);
+
+        if let Some(kind) = self.holding {
+            kind.draw_at(self.x, self.y - 30.0);
+        }
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 4.0, self.y + 6.0, 1.8, eye);
+        draw_circle(self.x + 4.0, self.y + 6.0, 1.8, eye);
+    }
+}
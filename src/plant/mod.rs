@@ -1,7 +1,38 @@
+pub mod blover;
+pub mod cabbage_pult;
+pub mod cactus;
 pub mod cherry_bomb;
+pub mod chomper;
+pub mod coffee_bean;
+pub mod doom_shroom;
+pub mod fume_shroom;
+pub mod garlic;
+pub mod gatling_pea;
+pub mod gloom_shroom;
+pub mod ice_shroom;
+pub mod jalapeno;
+pub mod kernel_pult;
+pub mod magnet_shroom;
+pub mod melon_pult;
 pub mod peashooter;
+#[allow(clippy::module_inception)]
 pub mod plant;
 pub mod potato_mine;
+pub mod puff_shroom;
+pub mod pumpkin;
+pub mod repeater;
+pub mod scaredy_shroom;
 pub mod slow_peashooter;
+pub mod spikeweed;
+pub mod split_pea;
+pub mod squash;
+pub mod starfruit;
+pub mod sun_shroom;
 pub mod sunflower;
+pub mod tall_nut;
+pub mod threepeater;
+pub mod torchwood;
+pub mod twin_sunflower;
+pub mod umbrella_leaf;
 pub mod wallnut;
+pub mod winter_melon;
@@ -1,12 +1,17 @@
-use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
 use crate::factory::projectile_factory::ProjectileKind;
 use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
 
+const PEA: PeaKind = PeaKind::Normal;
+
 pub struct Peashooter {
     pub x: f32,
     pub y: f32,
+    pub row: usize,
     pub cooldown: f32,
     pub timer: f32,
     pub health: i32,
@@ -15,10 +20,11 @@ pub struct Peashooter {
 }
 
 impl Peashooter {
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
         Self {
             x,
             y,
+            row,
             cooldown: 1.5,
             timer: 0.0,
             health: 100,
@@ -29,10 +35,11 @@ impl Peashooter {
 
     fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
         for z in zombies {
-            if (z.y() - self.y).abs() < TILE_HEIGHT / 2.0
+            if z.row() == self.row
                 && z.x() > self.x
                 && z.x() - self.x <= self.attack_range
                 && !z.is_dead()
+                && (z.is_grounded() || PEA.hits_air())
             {
                 return true;
             }
@@ -42,12 +49,18 @@ impl Peashooter {
 }
 
 impl Plant for Peashooter {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Peashooter
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.health
     }
@@ -59,7 +72,7 @@ impl Plant for Peashooter {
         self.health -= amount;
     }
 
-    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Option<PlantAction> {
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
         self.timer -= dt;
 
         let has_target = self.has_target(zombies);
@@ -67,13 +80,16 @@ impl Plant for Peashooter {
 
         if has_target && self.timer <= 0.0 {
             self.timer = self.cooldown;
-            return Some(PlantAction::Shoot {
-                kind: ProjectileKind::Normal,
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Pea(PEA),
                 x: self.x + 25.0,
                 y: self.y,
-            });
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
         }
-        None
+        Vec::new()
     }
 
     fn draw(&self) {
@@ -1,30 +1,149 @@
-use crate::{factory::projectile_factory::ProjectileKind, zombie::zombie::Zombie};
-use macroquad::prelude::*;
+use crate::{
+    factory::{plant_factory::PlantType, projectile_factory::ProjectileKind},
+    projectile::projectile::Direction,
+    zombie::{threat::Threat, zombie::Zombie},
+};
 
 #[derive(Debug)]
 pub enum PlantAction {
+    /// Fire a projectile `delay` seconds from now, so one update can queue a burst
     Shoot {
         kind: ProjectileKind,
         x: f32,
         y: f32,
+        /// Lane the projectile should travel along; may differ from the plant's own
+        row: usize,
+        direction: Direction,
+        delay: f32,
+    },
+    /// Swallow the zombie at this index of the slice passed to `update`
+    Devour {
+        target: usize,
+    },
+    /// Hurt the zombie at this index of the slice passed to `update`, going around any shield
+    Damage {
+        target: usize,
+        amount: i32,
+    },
+    /// Pull the metal accessory off the zombie at this index of the slice passed to `update`
+    PullAccessory {
+        target: usize,
     },
     ProduceSun {
         x: f32,
         y: f32,
+        value: i32,
+    },
+    /// Freeze every zombie on the lawn, then leave them slowed for `chill` seconds
+    FreezeAll {
+        duration: f32,
+        chill: f32,
+    },
+    /// Leave the tile at (x, y) unplantable for `duration` seconds
+    Crater {
+        x: f32,
+        y: f32,
+        duration: f32,
+    },
+    /// Blow every flying zombie off the lawn
+    BlowAway,
+    /// Apply `effect` to the other plants on the tile at (x, y)
+    AffectOccupant {
+        x: f32,
+        y: f32,
+        effect: PlantEffect,
     },
 }
 
+/// Something one plant does to another it is placed on
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlantEffect {
+    /// Wakes a sleeping mushroom for good
+    Wake,
+}
+
+impl PlantEffect {
+    /// Whether the effect would do anything to `plant`
+    pub fn accepts(&self, plant: &dyn Plant) -> bool {
+        match self {
+            PlantEffect::Wake => plant.is_sleeping(),
+        }
+    }
+
+    pub fn apply(&self, plant: &mut dyn Plant) {
+        match self {
+            PlantEffect::Wake => plant.wake(),
+        }
+    }
+}
+
+/// Which slot of a tile a plant occupies, listed bottom to top
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PlantLayer {
+    /// Flat on the ground; zombies walk over it instead of eating it
+    Ground,
+    Main,
+    /// Worn over the main plant and bitten first
+    Shell,
+    /// Perched on whatever is already there; zombies can't reach it
+    Top,
+}
+
+impl PlantLayer {
+    /// Only a shell can go over a main plant, and anything can sit on top;
+    /// any other pair needs its own tile
+    pub fn shares_tile_with(&self, other: PlantLayer) -> bool {
+        match (self, other) {
+            (PlantLayer::Top, PlantLayer::Top) => false,
+            (PlantLayer::Top, _) | (_, PlantLayer::Top) => true,
+            (PlantLayer::Main, PlantLayer::Shell) | (PlantLayer::Shell, PlantLayer::Main) => true,
+            _ => false,
+        }
+    }
+}
+
 pub trait Plant {
+    fn plant_type(&self) -> PlantType;
     fn x(&self) -> f32;
     fn y(&self) -> f32;
+    /// Lawn row the plant is planted in
+    fn row(&self) -> usize;
     fn health(&self) -> i32;
     fn take_damage(&mut self, amount: i32);
 
-    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Option<PlantAction>;
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction>;
     fn is_tall(&self) -> bool;
     fn draw(&self);
 
     fn is_dead(&self) -> bool {
         self.health() <= 0
     }
+
+    fn layer(&self) -> PlantLayer {
+        PlantLayer::Main
+    }
+
+    /// Sleeping plants are skipped by the game loop
+    fn is_sleeping(&self) -> bool {
+        false
+    }
+    /// Called when placed in daylight; only mushrooms actually doze off
+    fn sleep(&mut self) {}
+    /// Undoes `sleep`; only a Coffee Bean calls it
+    fn wake(&mut self) {}
+
+    /// Peas flying through this plant's tile catch fire
+    fn ignites_peas(&self) -> bool {
+        false
+    }
+
+    /// Stops `threat` from landing on the plant at (x, y); true if it was blocked
+    fn intercept(&mut self, _threat: &Threat, _x: f32, _y: f32) -> bool {
+        false
+    }
+
+    /// Zombies that bite this plant give up and move to a neighbouring lane
+    fn diverts_zombies(&self) -> bool {
+        false
+    }
 }
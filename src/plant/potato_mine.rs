@@ -1,13 +1,15 @@
 use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
 use crate::factory::projectile_factory::ProjectileKind;
 use crate::plant::plant::{Plant, PlantAction};
-use crate::projectile::projectile::Instakill;
+use crate::projectile::projectile::{BlastShape, Direction, Instakill};
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
 
 pub struct PotatoMine {
     pub x: f32,
     pub y: f32,
+    pub row: usize,
     pub timer: f32,
     pub health: i32,
     pub attack_range: f32,
@@ -15,10 +17,11 @@ pub struct PotatoMine {
 }
 
 impl PotatoMine {
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
         Self {
             x,
             y,
+            row,
             timer: 14.0,
             health: 100,
             attack_range: TILE_SIZE / 2.0,
@@ -28,10 +31,11 @@ impl PotatoMine {
 
     fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
         for z in zombies {
-            if (z.y() - self.y).abs() < TILE_HEIGHT / 2.0
+            if z.row() == self.row
                 && z.x() > self.x
                 && z.x() - self.x <= self.attack_range
                 && !z.is_dead()
+                && z.is_grounded()
             {
                 return true;
             }
@@ -41,12 +45,18 @@ impl PotatoMine {
 }
 
 impl Plant for PotatoMine {
+    fn plant_type(&self) -> PlantType {
+        PlantType::PotatoMine
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.health
     }
@@ -58,7 +68,7 @@ impl Plant for PotatoMine {
         self.health -= amount;
     }
 
-    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Option<PlantAction> {
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
         self.timer -= dt;
 
         if !self.is_attacking {
@@ -71,17 +81,22 @@ impl Plant for PotatoMine {
         } else {
             if self.timer <= 0.0 {
                 self.health = 0;
-                return Some(PlantAction::Shoot {
+                return vec![PlantAction::Shoot {
                     kind: ProjectileKind::Instakill {
-                        radius: TILE_SIZE * 1.5,
+                        shape: BlastShape::Circle {
+                            radius: TILE_SIZE * 1.5,
+                        },
                         tier: Instakill::Low,
                     },
                     x: self.x,
                     y: self.y,
-                });
+                    row: self.row,
+                    direction: Direction::Forward,
+                    delay: 0.0,
+                }];
             }
         }
-        None
+        Vec::new()
     }
 
     fn draw(&self) {
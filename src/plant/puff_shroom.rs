@@ -0,0 +1,116 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct PuffShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub sleeping: bool,
+}
+
+impl PuffShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: TILE_SIZE * 3.0,
+            sleeping: false,
+        }
+    }
+
+    fn spore(&self) -> PeaKind {
+        PeaKind::Spore {
+            range: self.attack_range,
+        }
+    }
+
+    fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        for z in zombies {
+            if z.row() == self.row
+                && z.x() > self.x
+                && z.x() - self.x <= self.attack_range
+                && !z.is_dead()
+                && (z.is_grounded() || self.spore().hits_air())
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Plant for PuffShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::PuffShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        if self.has_target(zombies) && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Pea(self.spore()),
+                x: self.x + 12.0,
+                y: self.y + 8.0,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // small stalk and cap, sitting low in the tile
+        draw_rectangle(self.x - 5.0, self.y + 4.0, 10.0, 12.0, BEIGE);
+        draw_ellipse(self.x, self.y + 4.0, 14.0, 9.0, 0.0, VIOLET);
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 3.0, self.y + 9.0, 1.5, eye);
+        draw_circle(self.x + 3.0, self.y + 9.0, 1.5, eye);
+    }
+}
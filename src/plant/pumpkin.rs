@@ -0,0 +1,64 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction, PlantLayer};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Pumpkin {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub current_health: i32,
+    max_health: i32,
+}
+
+impl Pumpkin {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            current_health: 500,
+            max_health: 500,
+        }
+    }
+}
+
+impl Plant for Pumpkin {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Pumpkin
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.current_health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn layer(&self) -> PlantLayer {
+        PlantLayer::Shell
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.current_health -= amount;
+    }
+
+    fn update(&mut self, _dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // see-through shell so the plant inside stays visible
+        let hp_ratio = self.current_health as f32 / self.max_health as f32;
+        let shell = Color::new(1.0, 0.55, 0.0, 0.35 + 0.4 * hp_ratio);
+        draw_ellipse_lines(self.x, self.y + 6.0, 34.0, 26.0, 0.0, 6.0, shell);
+        draw_line(self.x, self.y - 20.0, self.x, self.y - 28.0, 3.0, DARKGREEN);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct ScaredyShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    // hides from any zombie this close, in its own lane or the next ones over
+    pub scare_range: f32,
+    pub hiding: bool,
+    pub sleeping: bool,
+}
+
+impl ScaredyShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: 800.0, // basically whole row
+            scare_range: TILE_SIZE * 1.5,
+            hiding: false,
+            sleeping: false,
+        }
+    }
+
+    fn spore(&self) -> PeaKind {
+        PeaKind::Spore {
+            range: self.attack_range,
+        }
+    }
+
+    fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        for z in zombies {
+            if z.row() == self.row
+                && z.x() > self.x
+                && z.x() - self.x <= self.attack_range
+                && !z.is_dead()
+                && (z.is_grounded() || self.spore().hits_air())
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_scared(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        zombies.iter().any(|z| {
+            z.row().abs_diff(self.row) <= 1
+                && (z.x() - self.x).abs() <= self.scare_range
+                && !z.is_dead()
+        })
+    }
+}
+
+impl Plant for ScaredyShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::ScaredyShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        self.hiding = self.is_scared(zombies);
+        if self.hiding {
+            return Vec::new();
+        }
+
+        if self.has_target(zombies) && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Pea(self.spore()),
+                x: self.x + 15.0,
+                y: self.y - 10.0,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        if self.hiding {
+            // ducked down, only the cap shows
+            draw_ellipse(self.x, self.y + 14.0, 16.0, 8.0, 0.0, PINK);
+            return;
+        }
+
+        // tall stalk and cap
+        draw_rectangle(self.x - 4.0, self.y - 10.0, 8.0, 28.0, BEIGE);
+        draw_ellipse(self.x, self.y - 14.0, 16.0, 11.0, 0.0, PINK);
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 3.0, self.y - 4.0, 1.5, eye);
+        draw_circle(self.x + 3.0, self.y - 4.0, 1.5, eye);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction, PlantLayer};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Spikeweed {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    pub damage: i32,
+    pub cooldown: f32,
+    pub timer: f32,
+}
+
+impl Spikeweed {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 100,
+            damage: 20,
+            cooldown: 1.0,
+            timer: 0.0,
+        }
+    }
+
+    fn is_on_top(&self, z: &dyn Zombie) -> bool {
+        z.row() == self.row
+            && (z.x() - self.x).abs() < TILE_SIZE / 2.0
+            && !z.is_dead()
+            && z.is_grounded()
+    }
+}
+
+impl Plant for Spikeweed {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Spikeweed
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn layer(&self) -> PlantLayer {
+        PlantLayer::Ground
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        // a vehicle rolling over the spikes is wrecked, and so are the spikes
+        if let Some(target) = zombies
+            .iter()
+            .position(|z| self.is_on_top(z.as_ref()) && z.is_wheeled())
+        {
+            self.health = 0;
+            return vec![PlantAction::Damage {
+                target,
+                amount: zombies[target].health(),
+            }];
+        }
+
+        self.timer -= dt;
+        if self.timer > 0.0 {
+            return Vec::new();
+        }
+
+        let actions: Vec<PlantAction> = zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| self.is_on_top(z.as_ref()))
+            .map(|(target, _)| PlantAction::Damage {
+                target,
+                amount: self.damage,
+            })
+            .collect();
+        if !actions.is_empty() {
+            self.timer = self.cooldown;
+        }
+        actions
+    }
+
+    fn draw(&self) {
+        // flat base with a row of spikes
+        draw_ellipse(self.x, self.y + 20.0, 32.0, 8.0, 0.0, DARKGREEN);
+        for i in 0..5 {
+            let sx = self.x - 24.0 + i as f32 * 12.0;
+            draw_triangle(
+                vec2(sx - 5.0, self.y + 18.0),
+                vec2(sx + 5.0, self.y + 18.0),
+                vec2(sx, self.y + 2.0),
+                GRAY,
+            );
+        }
+    }
+}
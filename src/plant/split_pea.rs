@@ -0,0 +1,130 @@
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const PEA: PeaKind = PeaKind::Normal;
+
+pub struct SplitPea {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub is_attacking: bool,
+    pub back_burst: usize,
+    pub burst_gap: f32,
+}
+
+impl SplitPea {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: 800.0, // basically whole row
+            is_attacking: false,
+            back_burst: 2,
+            burst_gap: 0.15,
+        }
+    }
+
+    fn has_target(&self, zombies: &[Box<dyn Zombie>], direction: Direction) -> bool {
+        for z in zombies {
+            let ahead = (z.x() - self.x) * direction.sign();
+            if z.row() == self.row
+                && ahead > 0.0
+                && ahead <= self.attack_range
+                && !z.is_dead()
+                && (z.is_grounded() || PEA.hits_air())
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Plant for SplitPea {
+    fn plant_type(&self) -> PlantType {
+        PlantType::SplitPea
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        let front = self.has_target(zombies, Direction::Forward);
+        let back = self.has_target(zombies, Direction::Backward);
+        self.is_attacking = front || back;
+
+        if !self.is_attacking || self.timer > 0.0 {
+            return Vec::new();
+        }
+        self.timer = self.cooldown;
+
+        // one pea forward, two out of the back head
+        let mut actions = Vec::new();
+        if front {
+            actions.push(PlantAction::Shoot {
+                kind: ProjectileKind::Pea(PEA),
+                x: self.x + 25.0,
+                y: self.y,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            });
+        }
+        if back {
+            actions.extend((0..self.back_burst).map(|i| PlantAction::Shoot {
+                kind: ProjectileKind::Pea(PEA),
+                x: self.x - 25.0,
+                y: self.y,
+                row: self.row,
+                direction: Direction::Backward,
+                delay: i as f32 * self.burst_gap,
+            }));
+        }
+        actions
+    }
+
+    fn draw(&self) {
+        // Body
+        draw_circle(self.x, self.y, 20.0, GREEN);
+        // Front cannon
+        draw_circle(self.x + 20.0, self.y, 10.0, DARKGREEN);
+        // Back head
+        draw_circle(self.x - 18.0, self.y - 8.0, 12.0, GREEN);
+        draw_circle(self.x - 30.0, self.y - 8.0, 7.0, DARKGREEN);
+
+        // Eyes
+        draw_circle(self.x - 16.0, self.y - 12.0, 2.0, BLACK);
+        draw_circle(self.x + 6.0, self.y - 5.0, 2.0, BLACK);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::projectile::{BlastShape, Direction, Instakill};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const LEAP_TIME: f32 = 0.5;
+
+pub struct Squash {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    pub attack_range: f32,
+    // where it will land once it has spotted a zombie
+    pub target_x: Option<f32>,
+    pub start_x: f32,
+    pub timer: f32,
+}
+
+impl Squash {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 100,
+            attack_range: TILE_SIZE,
+            target_x: None,
+            start_x: x,
+            timer: 0.0,
+        }
+    }
+
+    /// Closest zombie within a tile on either side
+    fn find_target(&self, zombies: &[Box<dyn Zombie>]) -> Option<f32> {
+        zombies
+            .iter()
+            .filter(|z| {
+                z.row() == self.row
+                    && (z.x() - self.x).abs() <= self.attack_range
+                    && !z.is_dead()
+                    && z.is_grounded()
+            })
+            .map(|z| z.x())
+            .min_by(|a, b| (a - self.x).abs().total_cmp(&(b - self.x).abs()))
+    }
+}
+
+impl Plant for Squash {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Squash
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        let Some(target_x) = self.target_x else {
+            self.target_x = self.find_target(zombies);
+            return Vec::new();
+        };
+
+        // leap over to the target, then crush everything in that spot
+        self.timer += dt;
+        let progress = (self.timer / LEAP_TIME).min(1.0);
+        self.x = self.start_x + (target_x - self.start_x) * progress;
+
+        if progress >= 1.0 {
+            self.health = 0;
+            return vec![PlantAction::Shoot {
+                kind: ProjectileKind::Instakill {
+                    shape: BlastShape::Circle {
+                        radius: TILE_SIZE / 2.0,
+                    },
+                    tier: Instakill::High,
+                },
+                x: target_x,
+                y: self.y,
+                row: self.row,
+                direction: Direction::Forward,
+                delay: 0.0,
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // arc up during the leap
+        let lift = if self.target_x.is_some() {
+            let progress = (self.timer / LEAP_TIME).min(1.0);
+            (progress * std::f32::consts::PI).sin() * 40.0
+        } else {
+            0.0
+        };
+        let y = self.y - lift;
+
+        draw_rectangle(self.x - 20.0, y - 18.0, 40.0, 38.0, DARKGREEN);
+        draw_rectangle(self.x - 16.0, y - 24.0, 32.0, 8.0, GREEN);
+
+        // frowning eyes
+        draw_circle(self.x - 8.0, y - 4.0, 3.0, WHITE);
+        draw_circle(self.x + 8.0, y - 4.0, 3.0, WHITE);
+        draw_circle(self.x - 8.0, y - 4.0, 1.5, BLACK);
+        draw_circle(self.x + 8.0, y - 4.0, 1.5, BLACK);
+        draw_line(self.x - 13.0, y - 11.0, self.x - 4.0, y - 8.0, 2.0, BLACK);
+        draw_line(self.x + 13.0, y - 11.0, self.x + 4.0, y - 8.0, 2.0, BLACK);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Starfruit {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub is_attacking: bool,
+}
+
+impl Starfruit {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            is_attacking: false,
+        }
+    }
+
+    /// Up, down, back, and the two forward diagonals
+    fn headings() -> [Vec2; 5] {
+        let diagonal = Vec2::from_angle(30f32.to_radians());
+        [
+            vec2(0.0, -1.0),
+            vec2(0.0, 1.0),
+            vec2(-1.0, 0.0),
+            vec2(diagonal.x, -diagonal.y),
+            diagonal,
+        ]
+    }
+
+    // any zombie lying close to one of the five lines of fire
+    fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        let origin = vec2(self.x, self.y);
+        zombies
+            .iter()
+            .filter(|z| !z.is_dead() && z.is_grounded())
+            .any(|z| {
+                let to_zombie = vec2(z.x(), z.y()) - origin;
+                Self::headings().iter().any(|h| {
+                    to_zombie.dot(*h) > 0.0 && h.perp_dot(to_zombie).abs() < TILE_SIZE / 2.0
+                })
+            })
+    }
+}
+
+impl Plant for Starfruit {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Starfruit
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        let has_target = self.has_target(zombies);
+        self.is_attacking = has_target;
+
+        if has_target && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            return Self::headings()
+                .into_iter()
+                .map(|heading| PlantAction::Shoot {
+                    kind: ProjectileKind::Star { heading },
+                    x: self.x,
+                    y: self.y,
+                    row: self.row,
+                    direction: Direction::Forward,
+                    delay: 0.0,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        draw_poly(self.x, self.y, 5, 22.0, -90.0, GOLD);
+        draw_poly(self.x, self.y, 5, 17.0, -90.0, YELLOW);
+
+        // eyes
+        draw_circle(self.x - 5.0, self.y - 3.0, 2.0, BLACK);
+        draw_circle(self.x + 5.0, self.y - 3.0, 2.0, BLACK);
+    }
+}
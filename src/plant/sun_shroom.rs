@@ -0,0 +1,112 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const SMALL_SUN: i32 = 15;
+const BIG_SUN: i32 = 25;
+
+pub struct SunShroom {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub sleeping: bool,
+    // seconds spent awake; grows up after `grow_time`
+    pub age: f32,
+    pub grow_time: f32,
+}
+
+impl SunShroom {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 5.0,
+            timer: 2.0,
+            health: 80,
+            sleeping: false,
+            age: 0.0,
+            grow_time: 60.0,
+        }
+    }
+
+    fn is_grown(&self) -> bool {
+        self.age >= self.grow_time
+    }
+}
+
+impl Plant for SunShroom {
+    fn plant_type(&self) -> PlantType {
+        PlantType::SunShroom
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+    fn sleep(&mut self) {
+        self.sleeping = true;
+    }
+    fn wake(&mut self) {
+        self.sleeping = false;
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.age += dt;
+        self.timer -= dt;
+        if self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            return vec![PlantAction::ProduceSun {
+                x: self.x,
+                y: self.y,
+                value: if self.is_grown() { BIG_SUN } else { SMALL_SUN },
+            }];
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        let scale = if self.is_grown() { 1.0 } else { 0.7 };
+
+        draw_rectangle(
+            self.x - 5.0 * scale,
+            self.y,
+            10.0 * scale,
+            16.0 * scale,
+            BEIGE,
+        );
+        draw_ellipse(self.x, self.y, 18.0 * scale, 12.0 * scale, 0.0, GOLD);
+        draw_circle(
+            self.x - 6.0 * scale,
+            self.y - 4.0 * scale,
+            3.0 * scale,
+            ORANGE,
+        );
+
+        // Eyes
+        let eye = if self.sleeping { DARKGRAY } else { BLACK };
+        draw_circle(self.x - 3.0, self.y + 6.0 * scale, 1.5, eye);
+        draw_circle(self.x + 3.0, self.y + 6.0 * scale, 1.5, eye);
+    }
+}
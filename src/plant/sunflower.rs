@@ -1,3 +1,5 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
 use crate::plant::plant::{Plant, PlantAction};
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
@@ -5,16 +7,18 @@ use macroquad::prelude::*;
 pub struct Sunflower {
     pub x: f32,
     pub y: f32,
+    pub row: usize,
     pub cooldown: f32,
     pub timer: f32,
     pub health: i32,
 }
 
 impl Sunflower {
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
         Self {
             x,
             y,
+            row,
             cooldown: 5.0,
             timer: 2.0,
             health: 80,
@@ -23,12 +27,18 @@ impl Sunflower {
 }
 
 impl Plant for Sunflower {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Sunflower
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.health
     }
@@ -40,16 +50,17 @@ impl Plant for Sunflower {
         self.health -= amount;
     }
 
-    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Option<PlantAction> {
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
         self.timer -= dt;
         if self.timer <= 0.0 {
             self.timer = self.cooldown;
-            return Some(PlantAction::ProduceSun {
+            return vec![PlantAction::ProduceSun {
                 x: self.x,
                 y: self.y,
-            });
+                value: SUN_VALUE,
+            }];
         }
-        None
+        Vec::new()
     }
 
     fn draw(&self) {
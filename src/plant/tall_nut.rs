@@ -0,0 +1,100 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct TallNut {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub current_health: i32,
+    max_health: i32,
+}
+
+impl TallNut {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            current_health: 1000,
+            max_health: 1000,
+        }
+    }
+}
+
+impl Plant for TallNut {
+    fn plant_type(&self) -> PlantType {
+        PlantType::TallNut
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.current_health
+    }
+    // stops vaulting and jumping zombies
+    fn is_tall(&self) -> bool {
+        true
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.current_health -= amount;
+    }
+
+    fn update(&mut self, _dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // taller than a Wall-nut, sticking up above the tile
+        draw_ellipse(self.x, self.y - 10.0, 18.0, 36.0, 0.0, BROWN);
+        draw_ellipse(self.x, self.y - 10.0, 16.0, 34.0, 0.0, DARKBROWN);
+
+        draw_circle(self.x - 6.0, self.y - 6.0, 3.5, WHITE);
+        draw_circle(self.x + 6.0, self.y - 6.0, 3.5, WHITE);
+        draw_circle(self.x - 6.0, self.y - 6.0, 1.8, BLACK);
+        draw_circle(self.x + 6.0, self.y - 6.0, 1.8, BLACK);
+
+        draw_line(
+            self.x - 6.0,
+            self.y + 7.0,
+            self.x + 6.0,
+            self.y + 7.0,
+            2.0,
+            BLACK,
+        );
+
+        // --- Damage cracks if HP is low ---
+        let hp_ratio = self.current_health as f32 / self.max_health as f32;
+        if hp_ratio < 0.66 {
+            // light cracks
+            draw_line(self.x - 8.0, self.y, self.x - 2.0, self.y + 6.0, 1.5, BLACK);
+        }
+        if hp_ratio < 0.33 {
+            // heavier cracks
+            draw_line(
+                self.x + 4.0,
+                self.y - 4.0,
+                self.x + 10.0,
+                self.y + 2.0,
+                1.5,
+                BLACK,
+            );
+            draw_line(
+                self.x - 3.0,
+                self.y + 10.0,
+                self.x + 2.0,
+                self.y + 14.0,
+                1.5,
+                BLACK,
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::factory::plant_factory::PlantType;
+use crate::factory::projectile_factory::ProjectileKind;
+use crate::grid::lanes_around;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::projectile::pea::PeaKind;
+use crate::projectile::projectile::Direction;
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const PEA: PeaKind = PeaKind::Normal;
+
+pub struct Threepeater {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+    pub attack_range: f32,
+    pub is_attacking: bool,
+}
+
+impl Threepeater {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 1.5,
+            timer: 0.0,
+            health: 100,
+            attack_range: 800.0, // basically whole row
+            is_attacking: false,
+        }
+    }
+
+    // a zombie in any of the three lanes wakes it up
+    fn has_target(&self, zombies: &[Box<dyn Zombie>]) -> bool {
+        let lanes = lanes_around(self.row);
+        for z in zombies {
+            if lanes.contains(&z.row())
+                && z.x() > self.x
+                && z.x() - self.x <= self.attack_range
+                && !z.is_dead()
+                && (z.is_grounded() || PEA.hits_air())
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Plant for Threepeater {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Threepeater
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+
+        let has_target = self.has_target(zombies);
+        self.is_attacking = has_target;
+
+        if has_target && self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            // one pea per lane, all leaving from the plant's head
+            return lanes_around(self.row)
+                .into_iter()
+                .map(|row| PlantAction::Shoot {
+                    kind: ProjectileKind::Pea(PEA),
+                    x: self.x + 25.0,
+                    y: self.y,
+                    row,
+                    direction: Direction::Forward,
+                    delay: 0.0,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // Body
+        draw_circle(self.x, self.y + 8.0, 18.0, GREEN);
+        // Three heads, fanned out
+        for dy in [-18.0, 0.0, 18.0] {
+            draw_circle(self.x + 10.0, self.y + dy - 4.0, 9.0, GREEN);
+            draw_circle(self.x + 20.0, self.y + dy - 4.0, 5.0, DARKGREEN);
+            draw_circle(self.x + 8.0, self.y + dy - 7.0, 1.5, BLACK);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct Torchwood {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    // drives the flicker animation
+    pub flame_time: f32,
+}
+
+impl Torchwood {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 100,
+            flame_time: 0.0,
+        }
+    }
+}
+
+impl Plant for Torchwood {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Torchwood
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+    fn ignites_peas(&self) -> bool {
+        true
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.flame_time += dt;
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        // Stump
+        draw_rectangle(self.x - 16.0, self.y - 10.0, 32.0, 32.0, BROWN);
+        draw_rectangle_lines(self.x - 16.0, self.y - 10.0, 32.0, 32.0, 2.0, DARKBROWN);
+
+        // Flames
+        let flicker = (self.flame_time * 12.0).sin() * 3.0;
+        draw_triangle(
+            vec2(self.x - 14.0, self.y - 10.0),
+            vec2(self.x + 14.0, self.y - 10.0),
+            vec2(self.x + flicker, self.y - 38.0),
+            ORANGE,
+        );
+        draw_triangle(
+            vec2(self.x - 7.0, self.y - 10.0),
+            vec2(self.x + 7.0, self.y - 10.0),
+            vec2(self.x - flicker, self.y - 26.0),
+            YELLOW,
+        );
+
+        // Eyes
+        draw_circle(self.x - 6.0, self.y + 2.0, 2.0, BLACK);
+        draw_circle(self.x + 6.0, self.y + 2.0, 2.0, BLACK);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+pub struct TwinSunflower {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub cooldown: f32,
+    pub timer: f32,
+    pub health: i32,
+}
+
+impl TwinSunflower {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            cooldown: 5.0,
+            timer: 2.0,
+            health: 80,
+        }
+    }
+}
+
+impl Plant for TwinSunflower {
+    fn plant_type(&self) -> PlantType {
+        PlantType::TwinSunflower
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.timer -= dt;
+        if self.timer <= 0.0 {
+            self.timer = self.cooldown;
+            // one sun from each head
+            return [-10.0, 10.0]
+                .into_iter()
+                .map(|dx| PlantAction::ProduceSun {
+                    x: self.x + dx,
+                    y: self.y,
+                    value: SUN_VALUE,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        for dx in [-12.0, 12.0] {
+            let hx = self.x + dx;
+            // Petals
+            draw_circle(hx, self.y, 14.0, YELLOW);
+            // Center
+            draw_circle(hx, self.y, 8.0, ORANGE);
+
+            // Eyes
+            draw_circle(hx - 3.0, self.y - 2.0, 1.2, BLACK);
+            draw_circle(hx + 3.0, self.y - 2.0, 1.2, BLACK);
+
+            // Smile
+            draw_line(hx - 3.0, self.y + 3.0, hx + 3.0, self.y + 3.0, 1.2, BLACK);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::constants::*;
+use crate::factory::plant_factory::PlantType;
+use crate::plant::plant::{Plant, PlantAction};
+use crate::zombie::threat::{Threat, ThreatKind};
+use crate::zombie::zombie::Zombie;
+use macroquad::prelude::*;
+
+const OPEN_TIME: f32 = 0.5;
+
+pub struct UmbrellaLeaf {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    // counts down while the umbrella is drawn open after blocking something
+    pub open_timer: f32,
+}
+
+impl UmbrellaLeaf {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
+        Self {
+            x,
+            y,
+            row,
+            health: 100,
+            open_timer: 0.0,
+        }
+    }
+}
+
+impl Plant for UmbrellaLeaf {
+    fn plant_type(&self) -> PlantType {
+        PlantType::UmbrellaLeaf
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+    fn is_tall(&self) -> bool {
+        false
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    // covers its own tile and the eight around it from anything coming down from above
+    fn intercept(&mut self, threat: &Threat, x: f32, y: f32) -> bool {
+        if threat.kind == ThreatKind::Bite
+            || (x - self.x).abs() > TILE_SIZE * 1.5
+            || (y - self.y).abs() > TILE_SIZE * 1.5
+        {
+            return false;
+        }
+        self.open_timer = OPEN_TIME;
+        true
+    }
+
+    fn update(&mut self, dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        self.open_timer -= dt;
+        Vec::new()
+    }
+
+    fn draw(&self) {
+        draw_rectangle(self.x - 2.0, self.y - 10.0, 4.0, 28.0, DARKGREEN);
+        if self.open_timer > 0.0 {
+            // spread wide over the neighbouring tiles
+            draw_ellipse(self.x, self.y - 14.0, 46.0, 14.0, 0.0, GREEN);
+            draw_ellipse_lines(self.x, self.y - 14.0, 46.0, 14.0, 0.0, 2.0, DARKGREEN);
+        } else {
+            draw_ellipse(self.x, self.y - 14.0, 20.0, 10.0, 0.0, GREEN);
+        }
+
+        // Eyes
+        draw_circle(self.x - 4.0, self.y + 6.0, 2.0, BLACK);
+        draw_circle(self.x + 4.0, self.y + 6.0, 2.0, BLACK);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::factory::plant_factory::PlantType;
 use crate::plant::plant::{Plant, PlantAction};
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
@@ -5,15 +6,17 @@ use macroquad::prelude::*;
 pub struct Wallnut {
     pub x: f32,
     pub y: f32,
+    pub row: usize,
     pub current_health: i32,
     max_health: i32,
 }
 
 impl Wallnut {
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: f32, y: f32, row: usize) -> Self {
         Self {
             x,
             y,
+            row,
             current_health: 500,
             max_health: 500,
         }
@@ -21,12 +24,18 @@ impl Wallnut {
 }
 
 impl Plant for Wallnut {
+    fn plant_type(&self) -> PlantType {
+        PlantType::Wallnut
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.current_health
     }
@@ -38,8 +47,8 @@ impl Plant for Wallnut {
         self.current_health -= amount;
     }
 
-    fn update(&mut self, dt: f32, zombies: &[Box<dyn Zombie>]) -> Option<PlantAction> {
-        None
+    fn update(&mut self, _dt: f32, _zombies: &[Box<dyn Zombie>]) -> Vec<PlantAction> {
+        Vec::new()
     }
 
     fn draw(&self) {
@@ -1,17 +1,23 @@
 use crate::{constants::*, factory::plant_factory::PlantType};
 use macroquad::prelude::*;
 
+// greys out Imitater copies, in the bar and on the selection screen
+pub const IMITATER_TINT: Color = Color::new(0.5, 0.5, 0.5, 0.55);
+
 pub struct UISlot {
     pub plant: PlantType,
     pub x: f32,
     pub y: f32,
     pub selected: bool,
     pub cooldown: f32,
+    /// Second card for a plant already in the bar, drawn greyed out
+    pub imitater: bool,
 }
 
 pub struct UIBar {
     pub slots: Vec<UISlot>,
-    pub selected: Option<PlantType>,
+    /// Index of the selected slot; plants can have more than one
+    pub selected: Option<usize>,
     pub sun_box_width: f32,
 }
 
@@ -29,6 +35,7 @@ impl UIBar {
                 y,
                 selected: false,
                 cooldown: 0.0,
+                imitater: selected_plants[..i].contains(plant),
             });
         }
 
@@ -73,7 +80,7 @@ impl UIBar {
                 for (i, slot) in self.slots.iter_mut().enumerate() {
                     if i == clicked_index {
                         slot.selected = true;
-                        self.selected = Some(slot.plant);
+                        self.selected = Some(i);
                     } else {
                         slot.selected = false;
                     }
@@ -113,6 +120,10 @@ impl UIBar {
             let center_y = slot.y + SLOT_SIZE / 2.0 - 6.0;
 
             slot.plant.draw_preview(center_x, center_y - 6.0);
+            slot.plant.draw_upgrade_marker(slot.x + SLOT_SIZE, slot.y);
+            if slot.imitater {
+                draw_rectangle(slot.x, slot.y, SLOT_SIZE, SLOT_SIZE, IMITATER_TINT);
+            }
 
             let cost_text = format!("{}", slot.plant.cost());
             let text_dim = measure_text(&cost_text, None, 20, 1.0);
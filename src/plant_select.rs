@@ -1,24 +1,100 @@
-use crate::{constants::*, factory::plant_factory::PlantType};
+use crate::{
+    campaign::{LEVELS, unlocked_plants},
+    constants::*,
+    factory::plant_factory::PlantType,
+    plant_bar::IMITATER_TINT,
+};
 use macroquad::prelude::*;
-use strum::IntoEnumIterator;
 
 pub struct PlantSelect {
     pub available: Vec<PlantType>,
     pub selected: Vec<PlantType>,
     pub warning_timer: f32,
     pub scroll_y: f32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub max_selected: usize,
+    pub back: bool,
+    /// The Imitater card was clicked; the next chosen plant gets a second copy
+    pub imitating: bool,
 }
 
+const IMITATER_DESCRIPTION: &str = "Pick a chosen plant to take a second copy of it.";
+
 impl PlantSelect {
-    pub fn new() -> Self {
+    pub fn new(available: Vec<PlantType>, max_selected: usize) -> Self {
         Self {
-            available: PlantType::iter().collect(),
+            available,
             selected: Vec::new(),
             warning_timer: 0.0,
             scroll_y: 0.0,
+            title: "Select Your Plants".to_string(),
+            subtitle: None,
+            max_selected,
+            back: false,
+            imitating: false,
         }
     }
 
+    /// The plant the Imitater has copied, i.e. the one selected twice
+    pub fn imitated(&self) -> Option<PlantType> {
+        self.selected
+            .iter()
+            .enumerate()
+            .find(|(i, p)| self.selected[..*i].contains(p))
+            .map(|(_, p)| *p)
+    }
+
+    fn click_plant(&mut self, plant: PlantType) {
+        if self.imitating {
+            // only plants already chosen can be copied, and only once
+            if self.selected.contains(&plant)
+                && self.imitated().is_none()
+                && self.selected.len() < self.max_selected
+            {
+                self.selected.push(plant);
+            }
+            self.imitating = false;
+        } else if self.selected.contains(&plant) {
+            self.selected.retain(|p| *p != plant);
+        } else if self.selected.len() < self.max_selected {
+            self.selected.push(plant);
+        }
+    }
+
+    fn click_imitater(&mut self) {
+        if let Some(copy) = self.imitated() {
+            let last = self.selected.iter().rposition(|p| *p == copy);
+            if let Some(last) = last {
+                self.selected.remove(last);
+            }
+        } else {
+            self.imitating = !self.imitating;
+        }
+    }
+
+    /// Seed selection for an Adventure level, limited to that level's slots
+    pub fn for_level(level: usize, levels_completed: usize) -> Self {
+        let def = &LEVELS[level];
+        let mut select = Self::new(unlocked_plants(levels_completed), def.seed_slots);
+        select.title = format!("Level {}: {}", level + 1, def.name);
+        select.subtitle = Some(format!(
+            "Adventure progress: {}/{} levels complete, {} seed slots",
+            levels_completed.min(LEVELS.len()),
+            LEVELS.len(),
+            def.seed_slots,
+        ));
+        select
+    }
+
+    /// Loadout change between Survival rounds, starting from the previous seeds
+    pub fn between_rounds(round: u32, previous: Vec<PlantType>, available: Vec<PlantType>) -> Self {
+        let mut select = Self::new(available, MAX_SELECTED_PLANTS);
+        select.title = format!("Round {} cleared! Change your plants", round);
+        select.selected = previous;
+        select
+    }
+
     pub fn update(&mut self) -> Option<Vec<PlantType>> {
         // screen mouse
         let (mx, my) = mouse_position();
@@ -45,7 +121,7 @@ impl PlantSelect {
         // compute layout & limits
         let cols = ((viewport_w - padding_x * 2.0) / spacing_x).floor() as usize;
         let cols = std::cmp::max(1, cols);
-        let rows = (self.available.len() + cols - 1) / cols;
+        let rows = (self.available.len() + 1).div_ceil(cols);
         let total_content_height = padding_y * 2.0 + rows as f32 * spacing_y;
         let max_scroll = (total_content_height - viewport_h).max(0.0);
         self.scroll_y = self.scroll_y.clamp(0.0, max_scroll);
@@ -61,7 +137,8 @@ impl PlantSelect {
                 && my >= viewport_y
                 && my <= viewport_y + viewport_h
             {
-                for (i, plant) in self.available.iter().enumerate() {
+                // the Imitater card sits after the last plant
+                for i in 0..=self.available.len() {
                     let row = i / cols;
                     let col = i % cols;
 
@@ -75,10 +152,9 @@ impl PlantSelect {
                         && world_mouse.y >= y
                         && world_mouse.y <= y + h
                     {
-                        if self.selected.contains(plant) {
-                            self.selected.retain(|p| p != plant);
-                        } else if self.selected.len() < MAX_SELECTED_PLANTS {
-                            self.selected.push(*plant);
+                        match self.available.get(i) {
+                            Some(&plant) => self.click_plant(plant),
+                            None => self.click_imitater(),
                         }
                         break;
                     }
@@ -91,6 +167,10 @@ impl PlantSelect {
             self.warning_timer -= get_frame_time();
         }
 
+        if is_key_pressed(KeyCode::Escape) {
+            self.back = true;
+        }
+
         // Enter pressed
         if is_key_pressed(KeyCode::Enter) {
             if !self.selected.is_empty() {
@@ -106,7 +186,24 @@ impl PlantSelect {
     pub fn draw(&self) {
         clear_background(LIGHTGRAY);
 
-        draw_text("Select Your Plants", 250.0, 80.0, 40.0, DARKGREEN);
+        let metrics = measure_text(&self.title, None, 40, 1.0);
+        draw_text(
+            &self.title,
+            (SCREEN_WIDTH - metrics.width) / 2.0,
+            80.0,
+            40.0,
+            DARKGREEN,
+        );
+        if let Some(subtitle) = &self.subtitle {
+            let metrics = measure_text(subtitle, None, 24, 1.0);
+            draw_text(
+                subtitle,
+                (SCREEN_WIDTH - metrics.width) / 2.0,
+                108.0,
+                24.0,
+                DARKGRAY,
+            );
+        }
 
         let (mx, my) = mouse_position();
         let mut hovered_description: Option<&str> = None;
@@ -136,7 +233,7 @@ impl PlantSelect {
         // compute layout
         let cols = ((viewport_w - padding_x * 2.0) / spacing_x).floor() as usize;
         let cols = std::cmp::max(1, cols);
-        let rows = (self.available.len() + cols - 1) / cols;
+        let rows = (self.available.len() + 1).div_ceil(cols);
         let total_content_height = padding_y * 2.0 + rows as f32 * spacing_y;
         let max_scroll = (total_content_height - viewport_h).max(0.0);
 
@@ -182,6 +279,7 @@ impl PlantSelect {
 
             draw_rectangle(x, y, w, h, if hovered { LIGHTGRAY } else { GRAY });
             plant.draw_preview(x + w / 2.0, y + 35.0);
+            plant.draw_upgrade_marker(x + w, y);
 
             // name
             let text = format!("{:?}", plant);
@@ -217,6 +315,45 @@ impl PlantSelect {
             }
         }
 
+        // Imitater card
+        {
+            let i = self.available.len();
+            let x = viewport_x + padding_x + (i % cols) as f32 * spacing_x;
+            let y = viewport_y + padding_y + (i / cols) as f32 * spacing_y;
+            let w = cell_w;
+            let h = cell_h;
+            let hovered = world_mouse.x >= x
+                && world_mouse.x <= x + w
+                && world_mouse.y >= y
+                && world_mouse.y <= y + h
+                && mx >= viewport_x
+                && mx <= viewport_x + viewport_w
+                && my >= viewport_y
+                && my <= viewport_y + viewport_h;
+
+            draw_rectangle(x, y, w, h, if hovered { LIGHTGRAY } else { GRAY });
+            draw_circle(x + w / 2.0, y + 35.0, 12.0, WHITE);
+            draw_circle(x + w / 2.0, y + 35.0, 9.0, DARKGRAY);
+            draw_rectangle(x + w / 2.0 - 7.0, y + 44.0, 14.0, 8.0, BROWN);
+
+            let text = "Imitater";
+            let metrics = measure_text(text, None, 18, 1.0);
+            draw_text(
+                text,
+                x + (w - metrics.width) / 2.0,
+                y + h - 20.0,
+                18.0,
+                BLACK,
+            );
+
+            if self.imitating || self.imitated().is_some() {
+                draw_rectangle_lines(x, y, w, h, 4.0, YELLOW);
+            }
+            if hovered {
+                hovered_description = Some(IMITATER_DESCRIPTION);
+            }
+        }
+
         // back to default camera
         set_default_camera();
 
@@ -261,7 +398,7 @@ impl PlantSelect {
         let preview_size = 60.0;
         let gap = 16.0;
         let mut x_offset = viewport_x + 120.0;
-        for plant in &self.selected {
+        for (i, plant) in self.selected.iter().enumerate() {
             if x_offset + preview_size > SCREEN_WIDTH - 40.0 {
                 break;
             }
@@ -269,6 +406,15 @@ impl PlantSelect {
             let cx = x_offset + preview_size / 2.0;
             let cy = selected_y + 30.0 + preview_size / 2.0;
             plant.draw_preview(cx, cy);
+            if self.selected[..i].contains(plant) {
+                draw_rectangle(
+                    x_offset,
+                    selected_y + 10.0,
+                    preview_size,
+                    preview_size,
+                    IMITATER_TINT,
+                );
+            }
 
             draw_rectangle_lines(
                 x_offset,
@@ -288,7 +434,7 @@ impl PlantSelect {
             28.0,
             RED,
         );
-        if self.selected.len() == MAX_SELECTED_PLANTS {
+        if self.selected.len() == self.max_selected {
             draw_text(
                 "Max plants selected!",
                 viewport_x,
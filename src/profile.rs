@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fs;
+
+use strum::IntoEnumIterator;
+
+use crate::constants::{MAX_SURVIVAL_SCORES, PROFILE_PATH};
+use crate::factory::{plant_factory::PlantType, zombie_factory::ZombieType};
+use crate::stats::MatchStats;
+
+/// Player progress that survives between runs, stored as a plain text file
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    pub games_played: u32,
+    pub lifetime: MatchStats,
+    pub achievements: HashSet<String>,
+    /// Best Survival: Endless runs, most rounds first
+    pub survival_scores: Vec<u32>,
+    /// Number of Adventure levels completed, in order
+    pub campaign_progress: usize,
+}
+
+impl Profile {
+    /// Loads the profile from disk, falling back to a fresh one
+    pub fn load() -> Self {
+        match fs::read_to_string(PROFILE_PATH) {
+            Ok(text) => Self::parse(&text),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if let Err(err) = fs::write(PROFILE_PATH, self.serialize()) {
+            eprintln!("failed to save profile: {err}");
+        }
+    }
+
+    pub fn record_match(&mut self, stats: &MatchStats) {
+        self.games_played += 1;
+        self.lifetime.merge(stats);
+    }
+
+    /// Records a Survival run; returns true if it is a new best
+    pub fn record_survival(&mut self, rounds: u32) -> bool {
+        let best = self.survival_scores.first().is_none_or(|&b| rounds > b);
+        self.survival_scores.push(rounds);
+        self.survival_scores.sort_unstable_by(|a, b| b.cmp(a));
+        self.survival_scores.truncate(MAX_SURVIVAL_SCORES);
+        best
+    }
+
+    /// Marks an Adventure level complete; returns true the first time
+    pub fn complete_level(&mut self, level: usize) -> bool {
+        if level < self.campaign_progress {
+            return false;
+        }
+        self.campaign_progress = level + 1;
+        true
+    }
+
+    fn parse(text: &str) -> Self {
+        let mut profile = Self::default();
+        let stats = &mut profile.lifetime;
+
+        for line in text.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.as_slice() {
+                ["games_played", n] => profile.games_played = n.parse().unwrap_or(0),
+                ["time_survived", n] => stats.time_survived = n.parse().unwrap_or(0.0),
+                ["sun_produced", n] => stats.sun_produced = n.parse().unwrap_or(0),
+                ["sun_collected", n] => stats.sun_collected = n.parse().unwrap_or(0),
+                ["kill", name, n] => {
+                    if let Some(zombie) = parse_name::<ZombieType>(name) {
+                        stats.kills.insert(zombie, n.parse().unwrap_or(0));
+                    }
+                }
+                ["placed", name, n] => {
+                    if let Some(plant) = parse_name::<PlantType>(name) {
+                        stats.plants_placed.insert(plant, n.parse().unwrap_or(0));
+                    }
+                }
+                ["lost", name, n] => {
+                    if let Some(plant) = parse_name::<PlantType>(name) {
+                        stats.plants_lost.insert(plant, n.parse().unwrap_or(0));
+                    }
+                }
+                ["campaign_progress", n] => profile.campaign_progress = n.parse().unwrap_or(0),
+                ["survival_score", n] => {
+                    if let Ok(rounds) = n.parse() {
+                        profile.survival_scores.push(rounds);
+                    }
+                }
+                ["achievement", id] => {
+                    profile.achievements.insert(id.to_string());
+                }
+                ["damage", name, n] => {
+                    if let Some(plant) = parse_name::<PlantType>(name) {
+                        stats.damage_dealt.insert(plant, n.parse().unwrap_or(0));
+                    }
+                }
+                _ => {}
+            }
+        }
+        profile
+    }
+
+    fn serialize(&self) -> String {
+        let stats = &self.lifetime;
+        let mut out = String::new();
+
+        out.push_str(&format!("games_played {}\n", self.games_played));
+        out.push_str(&format!("campaign_progress {}\n", self.campaign_progress));
+        out.push_str(&format!("time_survived {}\n", stats.time_survived));
+        out.push_str(&format!("sun_produced {}\n", stats.sun_produced));
+        out.push_str(&format!("sun_collected {}\n", stats.sun_collected));
+        for (zombie, n) in &stats.kills {
+            out.push_str(&format!("kill {:?} {}\n", zombie, n));
+        }
+        for (plant, n) in &stats.plants_placed {
+            out.push_str(&format!("placed {:?} {}\n", plant, n));
+        }
+        for (plant, n) in &stats.plants_lost {
+            out.push_str(&format!("lost {:?} {}\n", plant, n));
+        }
+        for (plant, n) in &stats.damage_dealt {
+            out.push_str(&format!("damage {:?} {}\n", plant, n));
+        }
+        for rounds in &self.survival_scores {
+            out.push_str(&format!("survival_score {}\n", rounds));
+        }
+        for id in &self.achievements {
+            out.push_str(&format!("achievement {}\n", id));
+        }
+        out
+    }
+}
+
+/// Enum variants are stored by their `Debug` name
+fn parse_name<T: IntoEnumIterator + Debug>(name: &str) -> Option<T> {
+    T::iter().find(|v| format!("{:?}", v) == name)
+}
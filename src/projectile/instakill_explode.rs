@@ -1,25 +1,54 @@
-use super::projectile::{Instakill, Projectile};
+use super::projectile::{BlastShape, Instakill, Projectile};
+use crate::constants::*;
+use crate::event::{EventQueue, GameEvent};
+use crate::factory::plant_factory::PlantType;
+use crate::grid::row_center_y;
+use crate::plant::plant::Plant;
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
 
 pub struct InstakillExplode {
     pub x: f32,
     pub y: f32,
-    pub radius: f32,
+    pub row: usize,
+    pub shape: BlastShape,
     pub tier: Instakill,
     pub lifetime: f32,
     pub active: bool,
+    pub source: PlantType,
+    pub kills: u32,
 }
 
 impl InstakillExplode {
-    pub fn new(x: f32, y: f32, radius: f32, tier: Instakill) -> Self {
+    pub fn new(
+        x: f32,
+        y: f32,
+        row: usize,
+        shape: BlastShape,
+        tier: Instakill,
+        source: PlantType,
+    ) -> Self {
         Self {
             x,
             y,
-            radius,
+            row,
+            shape,
             tier,
             lifetime: 0.6,
             active: true,
+            source,
+            kills: 0,
+        }
+    }
+
+    fn covers(&self, zombie: &dyn Zombie) -> bool {
+        match self.shape {
+            BlastShape::Circle { radius } => {
+                let dx = zombie.x() - self.x;
+                let dy = zombie.y() - self.y;
+                (dx * dx + dy * dy).sqrt() <= radius
+            }
+            BlastShape::Lane => zombie.row() == self.row,
         }
     }
 }
@@ -29,22 +58,41 @@ impl Projectile for InstakillExplode {
         self.active
     }
 
-    fn update(&mut self, dt: f32, zombies: &mut Vec<Box<dyn Zombie>>) {
+    fn update(
+        &mut self,
+        dt: f32,
+        _plants: &[Box<dyn Plant>],
+        zombies: &mut Vec<Box<dyn Zombie>>,
+        events: &mut EventQueue,
+    ) {
         self.lifetime -= dt;
 
         for z in zombies.iter_mut() {
-            if z.is_dead() {
+            if z.is_dead() || z.health() <= 0 {
                 continue;
             }
-            let dx = z.x() - self.x;
-            let dy = z.y() - self.y;
-            if (dx * dx + dy * dy).sqrt() <= self.radius {
+            if self.covers(z.as_ref()) {
+                let before = z.health();
                 z.is_instakill(self.tier);
+                events.push(GameEvent::ProjectileHit {
+                    source: self.source,
+                    target: z.zombie_type(),
+                    damage: before - z.health().max(0),
+                    x: z.x(),
+                    y: z.y(),
+                });
+                if z.health() <= 0 {
+                    self.kills += 1;
+                }
             }
         }
 
         if self.lifetime <= 0.0 {
             self.active = false;
+            events.push(GameEvent::BlastFinished {
+                source: self.source,
+                kills: self.kills,
+            });
         }
     }
 
@@ -52,8 +100,17 @@ impl Projectile for InstakillExplode {
         if self.active {
             let alpha = (self.lifetime * 2.0).min(1.0);
             let color = Color::new(1.0, 0.2, 0.2, alpha);
-            draw_circle(self.x, self.y, self.radius, color);
-            draw_circle_lines(self.x, self.y, self.radius + 10.0, 3.0, ORANGE);
+            match self.shape {
+                BlastShape::Circle { radius } => {
+                    draw_circle(self.x, self.y, radius, color);
+                    draw_circle_lines(self.x, self.y, radius + 10.0, 3.0, ORANGE);
+                }
+                BlastShape::Lane => {
+                    let top = row_center_y(self.row) - TILE_SIZE / 2.0;
+                    draw_rectangle(0.0, top, SCREEN_WIDTH, TILE_SIZE, color);
+                    draw_rectangle_lines(0.0, top, SCREEN_WIDTH, TILE_SIZE, 3.0, ORANGE);
+                }
+            }
         }
     }
 }
@@ -0,0 +1,216 @@
+use crate::{
+    constants::*,
+    event::{EventQueue, GameEvent},
+    factory::plant_factory::PlantType,
+    grid::row_center_y,
+    plant::plant::Plant,
+    projectile::projectile::Projectile,
+    zombie::zombie::Zombie,
+};
+use macroquad::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LobKind {
+    Cabbage,
+    Kernel,
+    /// Stuns the zombie it lands on
+    Butter,
+    /// Splashes every zombie around where it lands
+    Melon,
+    /// A melon splash that also slows everything it hits
+    WinterMelon,
+}
+
+impl LobKind {
+    pub fn damage(&self) -> i32 {
+        match self {
+            LobKind::Cabbage => 40,
+            LobKind::Kernel => 20,
+            LobKind::Butter => 40,
+            LobKind::Melon | LobKind::WinterMelon => 80,
+        }
+    }
+}
+
+const HORIZONTAL_SPEED: f32 = 300.0;
+const MIN_FLIGHT_TIME: f32 = 0.5;
+const HITBOX: f32 = 35.0;
+const BUTTER_STUN: f32 = 4.0;
+const MELON_SPLASH_RADIUS: f32 = TILE_SIZE;
+const MELON_SPLASH_DAMAGE: i32 = 25;
+const WINTER_MELON_SLOW: f32 = 10.0;
+// how far a zombie can be from the aim point and still be the one being followed
+const FOLLOW_REACH: f32 = TILE_SIZE * 0.5;
+// how far it flies when nothing is in the lane to aim at
+const DEFAULT_RANGE: f32 = TILE_SIZE * 3.0;
+
+/// Arcs over the lane and comes down on the first zombie in it
+pub struct Lobbed {
+    pub start_x: f32,
+    pub base_y: f32,
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub kind: LobKind,
+    pub active: bool,
+    pub source: PlantType,
+    // picked on the first update, once the lawn can be seen
+    pub target_x: Option<f32>,
+    // a zombie was aimed at, so the shot keeps following it
+    pub locked: bool,
+    pub flight_time: f32,
+    pub elapsed: f32,
+}
+
+impl Lobbed {
+    pub fn new(x: f32, y: f32, row: usize, kind: LobKind, source: PlantType) -> Self {
+        Self {
+            start_x: x,
+            base_y: y,
+            x,
+            y,
+            row,
+            kind,
+            active: true,
+            source,
+            target_x: None,
+            locked: false,
+            flight_time: MIN_FLIGHT_TIME,
+            elapsed: 0.0,
+        }
+    }
+
+    fn can_hit(&self, z: &dyn Zombie) -> bool {
+        !z.is_dead() && z.is_grounded() && z.row() == self.row
+    }
+
+    fn aim(&mut self, zombies: &[Box<dyn Zombie>]) -> f32 {
+        let first = zombies
+            .iter()
+            .filter(|z| self.can_hit(z.as_ref()) && z.x() > self.start_x)
+            .map(|z| z.x())
+            .min_by(f32::total_cmp);
+        self.locked = first.is_some();
+        let target_x = first.unwrap_or(self.start_x + DEFAULT_RANGE);
+        self.flight_time = ((target_x - self.start_x) / HORIZONTAL_SPEED).max(MIN_FLIGHT_TIME);
+        self.target_x = Some(target_x);
+        target_x
+    }
+
+    /// Moves the aim onto where the zombie being aimed at has walked to since;
+    /// if it is gone, the shot comes down where it was last seen
+    fn follow(&mut self, zombies: &[Box<dyn Zombie>], target_x: f32) -> f32 {
+        let current = zombies
+            .iter()
+            .filter(|z| {
+                self.can_hit(z.as_ref())
+                    && z.x() > self.start_x
+                    && (z.x() - target_x).abs() < FOLLOW_REACH
+            })
+            .map(|z| z.x())
+            .min_by(|a, b| (a - target_x).abs().total_cmp(&(b - target_x).abs()));
+        let target_x = current.unwrap_or(target_x);
+        self.target_x = Some(target_x);
+        target_x
+    }
+
+    fn hit(&self, z: &mut Box<dyn Zombie>, damage: i32, events: &mut EventQueue) {
+        z.take_lobbed_damage(damage);
+        events.push(GameEvent::ProjectileHit {
+            source: self.source,
+            target: z.zombie_type(),
+            damage,
+            x: self.x,
+            y: self.y,
+        });
+    }
+
+    fn land(&mut self, zombies: &mut [Box<dyn Zombie>], events: &mut EventQueue) {
+        self.active = false;
+
+        let target = zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| self.can_hit(z.as_ref()) && (z.x() - self.x).abs() < HITBOX)
+            .min_by(|(_, a), (_, b)| (a.x() - self.x).abs().total_cmp(&(b.x() - self.x).abs()))
+            .map(|(i, _)| i);
+        let Some(target) = target else {
+            return;
+        };
+
+        self.hit(&mut zombies[target], self.kind.damage(), events);
+        match self.kind {
+            LobKind::Cabbage | LobKind::Kernel => {}
+            LobKind::Butter => zombies[target].apply_stun(BUTTER_STUN),
+            LobKind::Melon | LobKind::WinterMelon => {
+                let chills = self.kind == LobKind::WinterMelon;
+                if chills {
+                    zombies[target].apply_slow(WINTER_MELON_SLOW);
+                }
+                let centre = vec2(self.x, row_center_y(self.row));
+                for (i, z) in zombies.iter_mut().enumerate() {
+                    if i != target
+                        && !z.is_dead()
+                        && z.is_grounded()
+                        && vec2(z.x(), z.y()).distance(centre) <= MELON_SPLASH_RADIUS
+                    {
+                        self.hit(z, MELON_SPLASH_DAMAGE, events);
+                        if chills {
+                            z.apply_slow(WINTER_MELON_SLOW);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Projectile for Lobbed {
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn update(
+        &mut self,
+        dt: f32,
+        _plants: &[Box<dyn Plant>],
+        zombies: &mut Vec<Box<dyn Zombie>>,
+        events: &mut EventQueue,
+    ) {
+        let target_x = match self.target_x {
+            Some(x) if self.locked => self.follow(zombies, x),
+            Some(x) => x,
+            None => self.aim(zombies),
+        };
+
+        // parabola from the plant to the target, peaking halfway
+        self.elapsed += dt;
+        let t = (self.elapsed / self.flight_time).min(1.0);
+        let height = 80.0 + (target_x - self.start_x) * 0.15;
+        self.x = self.start_x + (target_x - self.start_x) * t;
+        self.y = self.base_y - height * 4.0 * t * (1.0 - t);
+
+        if t >= 1.0 {
+            self.land(zombies, events);
+        }
+    }
+
+    fn draw(&self) {
+        if !self.active {
+            return;
+        }
+        match self.kind {
+            LobKind::Cabbage => draw_circle(self.x, self.y, 8.0, LIME),
+            LobKind::Kernel => draw_circle(self.x, self.y, 5.0, GOLD),
+            LobKind::Butter => draw_rectangle(self.x - 7.0, self.y - 5.0, 14.0, 10.0, YELLOW),
+            LobKind::Melon => {
+                draw_circle(self.x, self.y, 11.0, DARKGREEN);
+                draw_circle_lines(self.x, self.y, 8.0, 1.5, GREEN);
+            }
+            LobKind::WinterMelon => {
+                draw_circle(self.x, self.y, 11.0, BLUE);
+                draw_circle_lines(self.x, self.y, 8.0, 1.5, WHITE);
+            }
+        }
+    }
+}
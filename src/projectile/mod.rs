@@ -1,4 +1,6 @@
 pub mod instakill_explode;
-pub mod normal_pea;
+pub mod lobbed;
+pub mod pea;
+#[allow(clippy::module_inception)]
 pub mod projectile;
-pub mod slow_pea;
+pub mod star;
@@ -0,0 +1,200 @@
+use crate::{
+    constants::*,
+    event::{EventQueue, GameEvent},
+    factory::plant_factory::PlantType,
+    grid::row_center_y,
+    plant::plant::Plant,
+    projectile::projectile::{Direction, Projectile},
+    zombie::zombie::Zombie,
+};
+use macroquad::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PeaKind {
+    Normal,
+    /// Slows the zombie it hits
+    Slow,
+    /// Double damage, and splashes zombies next to the one it hits
+    Fire,
+    /// Mushroom puff that fizzles out after `range` pixels
+    Spore {
+        range: f32,
+    },
+    /// Cactus spike, long enough to reach zombies in the air
+    Spike,
+}
+
+impl PeaKind {
+    pub fn damage(&self) -> i32 {
+        match self {
+            PeaKind::Normal => 20,
+            PeaKind::Slow => 10,
+            PeaKind::Fire => 40,
+            PeaKind::Spore { .. } => 20,
+            PeaKind::Spike => 20,
+        }
+    }
+
+    /// Everything else flies under airborne zombies
+    pub fn hits_air(&self) -> bool {
+        matches!(self, PeaKind::Spike)
+    }
+
+    /// What the pea turns into after flying through a burning plant
+    pub fn ignited(&self) -> PeaKind {
+        match self {
+            PeaKind::Slow => PeaKind::Normal,
+            PeaKind::Normal | PeaKind::Fire => PeaKind::Fire,
+            PeaKind::Spore { .. } | PeaKind::Spike => *self,
+        }
+    }
+}
+
+const SPLASH_RADIUS: f32 = TILE_SIZE * 0.6;
+const SPLASH_DAMAGE: i32 = 10;
+
+pub struct Pea {
+    pub start_x: f32,
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub velocity: Vec2,
+    pub kind: PeaKind,
+    pub active: bool,
+    pub source: PlantType,
+    // x of the last burning plant passed, so each one only changes the pea once
+    pub lit_at: Option<f32>,
+}
+
+impl Pea {
+    pub fn new(
+        x: f32,
+        y: f32,
+        row: usize,
+        kind: PeaKind,
+        direction: Direction,
+        source: PlantType,
+    ) -> Self {
+        Self {
+            start_x: x,
+            x,
+            y,
+            row,
+            velocity: vec2(300.0 * direction.sign(), 0.0),
+            kind,
+            active: true,
+            source,
+            lit_at: None,
+        }
+    }
+
+    fn pass_burning_plants(&mut self, plants: &[Box<dyn Plant>]) {
+        for p in plants {
+            if p.ignites_peas()
+                && p.row() == self.row
+                && (p.x() - self.x).abs() < TILE_SIZE / 2.0
+                && self.lit_at != Some(p.x())
+            {
+                self.kind = self.kind.ignited();
+                self.lit_at = Some(p.x());
+            }
+        }
+    }
+
+    fn hit(&self, z: &mut Box<dyn Zombie>, damage: i32, events: &mut EventQueue) {
+        z.take_damage(damage);
+        events.push(GameEvent::ProjectileHit {
+            source: self.source,
+            target: z.zombie_type(),
+            damage,
+            x: self.x,
+            y: self.y,
+        });
+    }
+}
+
+impl Projectile for Pea {
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn update(
+        &mut self,
+        dt: f32,
+        plants: &[Box<dyn Plant>],
+        zombies: &mut Vec<Box<dyn Zombie>>,
+        events: &mut EventQueue,
+    ) {
+        self.x += self.velocity.x * dt;
+        // drift into the target lane when fired from a neighbouring one
+        let target_y = row_center_y(self.row);
+        let step = LANE_DRIFT_SPEED * dt;
+        self.y += (target_y - self.y).clamp(-step, step);
+
+        // gone once it leaves the lawn at either end
+        if self.x < 0.0 || self.x > screen_width() {
+            self.active = false;
+            return;
+        }
+        if let PeaKind::Spore { range } = self.kind
+            && (self.x - self.start_x).abs() > range
+        {
+            self.active = false;
+            return;
+        }
+
+        self.pass_burning_plants(plants);
+
+        let Some(target) = zombies.iter().position(|z| {
+            !z.is_dead()
+                && (z.is_grounded() || self.kind.hits_air())
+                && z.row() == self.row
+                && (z.x() - self.x).abs() < 20.0
+        }) else {
+            return;
+        };
+
+        self.hit(&mut zombies[target], self.kind.damage(), events);
+        match self.kind {
+            PeaKind::Normal | PeaKind::Spore { .. } | PeaKind::Spike => {}
+            PeaKind::Slow => zombies[target].apply_slow(2.5),
+            PeaKind::Fire => {
+                for (i, z) in zombies.iter_mut().enumerate() {
+                    if i != target
+                        && !z.is_dead()
+                        && z.is_grounded()
+                        && z.row() == self.row
+                        && (z.x() - self.x).abs() < SPLASH_RADIUS
+                    {
+                        self.hit(z, SPLASH_DAMAGE, events);
+                    }
+                }
+            }
+        }
+        self.active = false;
+    }
+
+    fn draw(&self) {
+        if !self.active {
+            return;
+        }
+        match self.kind {
+            PeaKind::Normal => draw_circle(self.x, self.y, 5.0, DARKGREEN),
+            PeaKind::Slow => draw_circle(self.x, self.y, 6.0, DARKBLUE),
+            PeaKind::Spore { .. } => draw_circle(self.x, self.y, 4.0, VIOLET),
+            PeaKind::Spike => {
+                let tip = self.x + 8.0 * self.velocity.x.signum();
+                draw_triangle(
+                    vec2(tip, self.y),
+                    vec2(self.x, self.y - 3.0),
+                    vec2(self.x, self.y + 3.0),
+                    DARKGREEN,
+                );
+            }
+            PeaKind::Fire => {
+                draw_circle(self.x, self.y, 7.0, ORANGE);
+                draw_circle(self.x - 3.0 * self.velocity.x.signum(), self.y, 4.0, YELLOW);
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::event::EventQueue;
+use crate::plant::plant::Plant;
 use crate::zombie::zombie::Zombie;
 use macroquad::prelude::*;
 
@@ -10,6 +12,7 @@ pub enum Instakill {
 
 impl Instakill {
     // if zombie resists instakill, it loses this fraction of its max health instead
+    #[allow(dead_code)]
     pub fn hp_fraction(&self) -> f32 {
         match self {
             Instakill::Low => 0.10,
@@ -19,9 +22,42 @@ impl Instakill {
     }
 }
 
+/// Area an instakill blast covers
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlastShape {
+    Circle {
+        radius: f32,
+    },
+    /// Every zombie in the blast's row, end to end
+    Lane,
+}
+
+/// Which way along its lane a projectile travels
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+impl Direction {
+    pub fn sign(&self) -> f32 {
+        match self {
+            Direction::Forward => 1.0,
+            Direction::Backward => -1.0,
+        }
+    }
+}
+
 pub trait Projectile {
     fn is_active(&self) -> bool;
 
-    fn update(&mut self, dt: f32, zombies: &mut Vec<Box<dyn Zombie>>);
+    /// Plants are passed so projectiles can react to what they fly through
+    fn update(
+        &mut self,
+        dt: f32,
+        plants: &[Box<dyn Plant>],
+        zombies: &mut Vec<Box<dyn Zombie>>,
+        events: &mut EventQueue,
+    );
     fn draw(&self);
 }
@@ -0,0 +1,105 @@
+use crate::{
+    constants::*,
+    event::{EventQueue, GameEvent},
+    factory::plant_factory::PlantType,
+    plant::plant::Plant,
+    projectile::projectile::Projectile,
+    zombie::zombie::Zombie,
+};
+use macroquad::prelude::*;
+
+const SPEED: f32 = 300.0;
+const RADIUS: f32 = 7.0;
+const DAMAGE: i32 = 20;
+// half extents of a zombie's drawn body
+const ZOMBIE_HALF_WIDTH: f32 = 20.0;
+const ZOMBIE_HALF_HEIGHT: f32 = 40.0;
+
+/// Flies in a straight line at any angle and hits whatever zombie it touches, in any lane
+pub struct Star {
+    pub pos: Vec2,
+    pub velocity: Vec2,
+    pub active: bool,
+    pub source: PlantType,
+}
+
+impl Star {
+    pub fn new(x: f32, y: f32, heading: Vec2, source: PlantType) -> Self {
+        Self {
+            pos: vec2(x, y),
+            velocity: heading.normalize_or_zero() * SPEED,
+            active: true,
+            source,
+        }
+    }
+
+    fn touches(&self, z: &dyn Zombie) -> bool {
+        let closest = vec2(
+            self.pos
+                .x
+                .clamp(z.x() - ZOMBIE_HALF_WIDTH, z.x() + ZOMBIE_HALF_WIDTH),
+            self.pos
+                .y
+                .clamp(z.y() - ZOMBIE_HALF_HEIGHT, z.y() + ZOMBIE_HALF_HEIGHT),
+        );
+        closest.distance(self.pos) < RADIUS
+    }
+}
+
+impl Projectile for Star {
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn update(
+        &mut self,
+        dt: f32,
+        _plants: &[Box<dyn Plant>],
+        zombies: &mut Vec<Box<dyn Zombie>>,
+        events: &mut EventQueue,
+    ) {
+        self.pos += self.velocity * dt;
+
+        // gone once it leaves the lawn on any side
+        let lawn_bottom = UI_BAR_HEIGHT + ROWS as f32 * TILE_SIZE;
+        if self.pos.x < 0.0
+            || self.pos.x > screen_width()
+            || self.pos.y < UI_BAR_HEIGHT
+            || self.pos.y > lawn_bottom
+        {
+            self.active = false;
+            return;
+        }
+
+        let Some(z) = zombies
+            .iter_mut()
+            .find(|z| !z.is_dead() && z.is_grounded() && self.touches(z.as_ref()))
+        else {
+            return;
+        };
+
+        z.take_damage(DAMAGE);
+        events.push(GameEvent::ProjectileHit {
+            source: self.source,
+            target: z.zombie_type(),
+            damage: DAMAGE,
+            x: self.pos.x,
+            y: self.pos.y,
+        });
+        self.active = false;
+    }
+
+    fn draw(&self) {
+        if !self.active {
+            return;
+        }
+        draw_poly(
+            self.pos.x,
+            self.pos.y,
+            5,
+            RADIUS,
+            get_time() as f32 * 360.0,
+            GOLD,
+        );
+    }
+}
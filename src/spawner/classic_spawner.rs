@@ -0,0 +1,72 @@
+use std::cmp::min;
+
+use crate::constants::*;
+use crate::event::{EventQueue, GameEvent};
+use crate::factory::zombie_factory::random_zombie_type;
+use crate::spawner::spawner::{SpawnOrder, Spawner, random_lanes};
+use macroquad::prelude::*;
+
+/// Never-ending trickle of zombies that slowly ramps up
+pub struct ClassicSpawner {
+    pub timer: f32,
+    pub next_time: f32,
+    pub zombie_count: i32,
+    pub wave: u32,
+}
+
+impl ClassicSpawner {
+    pub fn new() -> Self {
+        Self {
+            timer: 0.0,
+            next_time: rand::gen_range(
+                NATURAL_ZOMBIE_MIN_SPAWN_INTERVAL,
+                NATURAL_ZOMBIE_MAX_SPAWN_INTERVAL,
+            ),
+            zombie_count: 0,
+            wave: 0,
+        }
+    }
+}
+
+impl Spawner for ClassicSpawner {
+    fn update(
+        &mut self,
+        dt: f32,
+        _zombies_alive: usize,
+        events: &mut EventQueue,
+    ) -> Vec<SpawnOrder> {
+        let mut orders = Vec::new();
+
+        self.timer += dt;
+        if self.timer >= self.next_time {
+            self.wave += 1;
+            events.push(GameEvent::WaveStarted {
+                wave: self.wave,
+                flag: false,
+            });
+
+            let spawn_amount = min(self.zombie_count / 5 + 1, MAX_ZOMBIE_SPAWN);
+            let count = rand::gen_range(1, spawn_amount + 1) as usize;
+
+            for row in random_lanes(count) {
+                orders.push(SpawnOrder {
+                    zombie_type: random_zombie_type(self.zombie_count as usize),
+                    row,
+                });
+                self.zombie_count += 1;
+            }
+
+            self.timer = 0.0;
+            self.next_time = rand::gen_range(
+                (NATURAL_ZOMBIE_MIN_SPAWN_INTERVAL - (self.zombie_count as f32 / 50.0)).max(2.0),
+                (NATURAL_ZOMBIE_MAX_SPAWN_INTERVAL - (self.zombie_count as f32 / 50.0)).max(4.0),
+            );
+        }
+
+        orders
+    }
+
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,5 @@
+pub mod classic_spawner;
+pub mod scripted_spawner;
+#[allow(clippy::module_inception)]
+pub mod spawner;
+pub mod survival_spawner;
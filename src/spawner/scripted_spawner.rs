@@ -0,0 +1,71 @@
+use crate::campaign::ScriptedWave;
+use crate::event::{EventQueue, GameEvent};
+use crate::factory::zombie_factory::ZombieType;
+use crate::spawner::spawner::{SpawnOrder, Spawner, random_lanes};
+use macroquad::rand::ChooseRandom;
+
+const SPAWN_GAP: f32 = 0.5;
+
+/// Plays back a level's fixed wave script
+pub struct ScriptedSpawner {
+    pub waves: &'static [ScriptedWave],
+    pub next_wave: usize,
+    pub timer: f32,
+    pub pending: Vec<ZombieType>,
+    pub gap_timer: f32,
+}
+
+impl ScriptedSpawner {
+    pub fn new(waves: &'static [ScriptedWave]) -> Self {
+        Self {
+            waves,
+            next_wave: 0,
+            timer: 0.0,
+            pending: Vec::new(),
+            gap_timer: 0.0,
+        }
+    }
+}
+
+impl Spawner for ScriptedSpawner {
+    fn update(
+        &mut self,
+        dt: f32,
+        _zombies_alive: usize,
+        events: &mut EventQueue,
+    ) -> Vec<SpawnOrder> {
+        let mut orders = Vec::new();
+
+        if let Some(wave) = self.waves.get(self.next_wave) {
+            self.timer += dt;
+            if self.timer >= wave.delay {
+                self.timer = 0.0;
+                self.next_wave += 1;
+                self.pending.extend_from_slice(wave.zombies);
+                self.pending.shuffle();
+                events.push(GameEvent::WaveStarted {
+                    wave: self.next_wave as u32,
+                    flag: wave.flag,
+                });
+            }
+        }
+
+        // spread each wave over the lanes, a few zombies at a time
+        self.gap_timer -= dt;
+        if self.gap_timer <= 0.0 && !self.pending.is_empty() {
+            let count = self.pending.len().min(2);
+            for row in random_lanes(count) {
+                if let Some(zombie_type) = self.pending.pop() {
+                    orders.push(SpawnOrder { zombie_type, row });
+                }
+            }
+            self.gap_timer = SPAWN_GAP;
+        }
+
+        orders
+    }
+
+    fn is_finished(&self) -> bool {
+        self.next_wave >= self.waves.len() && self.pending.is_empty()
+    }
+}
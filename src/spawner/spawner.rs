@@ -0,0 +1,27 @@
+use crate::constants::*;
+use crate::event::EventQueue;
+use crate::factory::zombie_factory::ZombieType;
+use macroquad::rand::ChooseRandom;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpawnOrder {
+    pub zombie_type: ZombieType,
+    pub row: usize,
+}
+
+pub trait Spawner {
+    /// Zombies that should enter the lawn this tick
+    fn update(&mut self, dt: f32, zombies_alive: usize, events: &mut EventQueue)
+    -> Vec<SpawnOrder>;
+
+    /// True once no more zombies will be spawned
+    fn is_finished(&self) -> bool;
+}
+
+/// Up to `count` distinct lanes in random order
+pub fn random_lanes(count: usize) -> Vec<usize> {
+    let mut lanes: Vec<usize> = (0..ROWS).collect();
+    lanes.shuffle();
+    lanes.truncate(count);
+    lanes
+}
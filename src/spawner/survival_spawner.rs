@@ -0,0 +1,115 @@
+use crate::constants::*;
+use crate::event::{EventQueue, GameEvent};
+use crate::factory::zombie_factory::{ZombieType, pick_rarity, zombies_by_rarity};
+use crate::spawner::spawner::{SpawnOrder, Spawner};
+use macroquad::prelude::*;
+use macroquad::rand::ChooseRandom;
+use strum::IntoEnumIterator;
+
+pub const WAVES_PER_ROUND: u32 = 10;
+// every FLAG_EVERY-th wave of a round is a huge wave
+pub const FLAG_EVERY: u32 = 5;
+
+const REPLANT_TIME: f32 = 20.0;
+const WAVE_INTERVAL: f32 = 25.0;
+const MIN_WAVE_INTERVAL: f32 = 6.0;
+const SPAWN_GAP: f32 = 0.4;
+
+/// One round of Survival: Endless, bought wave by wave from a point budget
+pub struct SurvivalSpawner {
+    pub round: u32,
+    pub wave: u32,
+    pub timer: f32,
+    pub pending: Vec<ZombieType>,
+    pub gap_timer: f32,
+}
+
+impl SurvivalSpawner {
+    pub fn new(round: u32) -> Self {
+        Self {
+            round,
+            wave: 0,
+            // first wave waits for the player to replant
+            timer: WAVE_INTERVAL - REPLANT_TIME,
+            pending: Vec::new(),
+            gap_timer: 0.0,
+        }
+    }
+
+    fn is_flag(wave: u32) -> bool {
+        wave.is_multiple_of(FLAG_EVERY)
+    }
+
+    fn budget(&self, wave: u32) -> u32 {
+        let base = self.round + wave / 2;
+        if Self::is_flag(wave) { base * 3 } else { base }
+    }
+
+    /// Spends the wave's budget on random zombies, rarer ones more likely in later rounds
+    fn buy_wave(&self, wave: u32) -> Vec<ZombieType> {
+        let cheapest = ZombieType::iter().map(|z| z.cost()).min().unwrap_or(1);
+        let difficulty = (self.round * 15 + wave) as usize;
+
+        let mut budget = self.budget(wave);
+        let mut bought = Vec::new();
+        let mut attempts = 0;
+        while budget >= cheapest && attempts < 100 {
+            attempts += 1;
+            let pool = zombies_by_rarity(pick_rarity(difficulty));
+            let zombie_type = *pool.choose().unwrap_or(&ZombieType::Basic);
+            if zombie_type.cost() <= budget {
+                budget -= zombie_type.cost();
+                bought.push(zombie_type);
+            }
+        }
+        bought
+    }
+}
+
+impl Spawner for SurvivalSpawner {
+    fn update(
+        &mut self,
+        dt: f32,
+        zombies_alive: usize,
+        events: &mut EventQueue,
+    ) -> Vec<SpawnOrder> {
+        let mut orders = Vec::new();
+
+        // release the current wave a zombie at a time
+        self.gap_timer -= dt;
+        if self.gap_timer <= 0.0
+            && let Some(zombie_type) = self.pending.pop()
+        {
+            orders.push(SpawnOrder {
+                zombie_type,
+                row: rand::gen_range(0, ROWS),
+            });
+            self.gap_timer = SPAWN_GAP;
+        }
+
+        if self.wave >= WAVES_PER_ROUND {
+            return orders;
+        }
+
+        // next wave comes on a timer, or early once the lawn is clear
+        self.timer += dt;
+        let lawn_clear = zombies_alive == 0 && self.pending.is_empty();
+        if self.timer >= WAVE_INTERVAL
+            || (lawn_clear && self.wave > 0 && self.timer >= MIN_WAVE_INTERVAL)
+        {
+            self.wave += 1;
+            self.timer = 0.0;
+            self.pending = self.buy_wave(self.wave);
+            events.push(GameEvent::WaveStarted {
+                wave: (self.round - 1) * WAVES_PER_ROUND + self.wave,
+                flag: Self::is_flag(self.wave),
+            });
+        }
+
+        orders
+    }
+
+    fn is_finished(&self) -> bool {
+        self.wave >= WAVES_PER_ROUND && self.pending.is_empty()
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::event::{EventListener, GameEvent};
+use crate::factory::{plant_factory::PlantType, zombie_factory::ZombieType};
+
+#[derive(Clone, Debug, Default)]
+pub struct MatchStats {
+    pub kills: HashMap<ZombieType, u32>,
+    pub sun_produced: i32,
+    pub sun_collected: i32,
+    pub plants_placed: HashMap<PlantType, u32>,
+    pub plants_lost: HashMap<PlantType, u32>,
+    pub damage_dealt: HashMap<PlantType, i32>,
+    pub time_survived: f32,
+    pub waves: u32,
+    pub rounds: u32,
+    pub won: bool,
+}
+
+impl MatchStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    /// Adds another match's numbers on top of these (used for lifetime totals)
+    pub fn merge(&mut self, other: &MatchStats) {
+        for (zombie, n) in &other.kills {
+            *self.kills.entry(*zombie).or_insert(0) += n;
+        }
+        for (plant, n) in &other.plants_placed {
+            *self.plants_placed.entry(*plant).or_insert(0) += n;
+        }
+        for (plant, n) in &other.plants_lost {
+            *self.plants_lost.entry(*plant).or_insert(0) += n;
+        }
+        for (plant, n) in &other.damage_dealt {
+            *self.damage_dealt.entry(*plant).or_insert(0) += n;
+        }
+        self.sun_produced += other.sun_produced;
+        self.sun_collected += other.sun_collected;
+        self.time_survived += other.time_survived;
+    }
+}
+
+impl EventListener for MatchStats {
+    fn on_event(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::ZombieDied { zombie_type, .. } => {
+                *self.kills.entry(zombie_type).or_insert(0) += 1;
+            }
+            GameEvent::PlantPlaced { plant_type, .. } => {
+                *self.plants_placed.entry(plant_type).or_insert(0) += 1;
+            }
+            GameEvent::PlantEaten { plant_type, .. } => {
+                *self.plants_lost.entry(plant_type).or_insert(0) += 1;
+            }
+            GameEvent::SunProduced { value, .. } => self.sun_produced += value,
+            GameEvent::SunCollected { value, .. } => self.sun_collected += value,
+            GameEvent::ProjectileHit { source, damage, .. } => {
+                *self.damage_dealt.entry(source).or_insert(0) += damage;
+            }
+            GameEvent::WaveStarted { wave, .. } => self.waves = self.waves.max(wave),
+            GameEvent::RoundCleared { round } => self.rounds = self.rounds.max(round),
+            _ => {}
+        }
+    }
+}
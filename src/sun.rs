@@ -20,7 +20,7 @@ pub struct Sun {
 
 impl Sun {
     /// Spawn a sun produced by a plant
-    pub fn from_plant(x: f32, y: f32) -> Self {
+    pub fn from_plant(x: f32, y: f32, value: i32) -> Self {
         let offset_x = if rand::gen_range(0, 2) == 0 {
             -20.0
         } else {
@@ -33,7 +33,7 @@ impl Sun {
             target_y,
             speed: 40.0,
             collected: false,
-            value: SUN_VALUE,
+            value,
             source: SunSource::Plant,
         }
     }
@@ -56,20 +56,20 @@ impl Sun {
     }
 
     pub fn update(&mut self, dt: f32) {
-        if !self.collected {
-            if self.y < self.target_y {
-                self.y += self.speed * dt;
-                if self.y > self.target_y {
-                    self.y = self.target_y;
-                }
+        if !self.collected && self.y < self.target_y {
+            self.y += self.speed * dt;
+            if self.y > self.target_y {
+                self.y = self.target_y;
             }
         }
     }
 
     pub fn draw(&self) {
         if !self.collected {
-            draw_circle(self.x, self.y, 15.0, YELLOW);
-            draw_circle(self.x, self.y, 10.0, ORANGE);
+            // smaller suns are worth less
+            let scale = (self.value as f32 / SUN_VALUE as f32).clamp(0.5, 1.5);
+            draw_circle(self.x, self.y, 15.0 * scale, YELLOW);
+            draw_circle(self.x, self.y, 10.0 * scale, ORANGE);
         }
     }
 
@@ -0,0 +1,57 @@
+use crate::constants::*;
+use macroquad::prelude::*;
+
+pub struct Toast {
+    pub title: String,
+    pub text: String,
+    pub timer: f32,
+}
+
+/// Notifications shown one at a time in the top-right corner
+pub struct Toasts {
+    pub queue: Vec<Toast>,
+}
+
+impl Toasts {
+    const DURATION: f32 = 3.0;
+
+    pub fn new() -> Self {
+        Self { queue: Vec::new() }
+    }
+
+    pub fn push(&mut self, title: &str, text: &str) {
+        self.queue.push(Toast {
+            title: title.to_string(),
+            text: text.to_string(),
+            timer: Self::DURATION,
+        });
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        if let Some(toast) = self.queue.first_mut() {
+            toast.timer -= dt;
+            if toast.timer <= 0.0 {
+                self.queue.remove(0);
+            }
+        }
+    }
+
+    pub fn draw(&self) {
+        let Some(toast) = self.queue.first() else {
+            return;
+        };
+
+        let w = 320.0;
+        let h = 60.0;
+        // slide in during the first and out during the last 0.3 seconds
+        let elapsed = Self::DURATION - toast.timer;
+        let slide = (elapsed / 0.3).min(toast.timer / 0.3).min(1.0);
+        let x = SCREEN_WIDTH - (w + 10.0) * slide;
+        let y = UI_BAR_HEIGHT + 10.0;
+
+        draw_rectangle(x, y, w, h, Color::new(0.1, 0.1, 0.1, 0.85));
+        draw_rectangle_lines(x, y, w, h, 2.0, GOLD);
+        draw_text(&toast.title, x + 12.0, y + 24.0, 24.0, GOLD);
+        draw_text(&toast.text, x + 12.0, y + 48.0, 18.0, WHITE);
+    }
+}
@@ -0,0 +1,79 @@
+use macroquad::prelude::*;
+
+/// Armor or gear a zombie wears or carries, with its own health pool
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AccessoryKind {
+    Cone,
+    Bucket,
+    ScreenDoor,
+    /// Keeps its zombie in the air until popped
+    Balloon,
+}
+
+impl AccessoryKind {
+    /// Metal gear can be pulled off by a Magnet-shroom
+    pub fn is_metal(&self) -> bool {
+        !matches!(self, AccessoryKind::Cone | AccessoryKind::Balloon)
+    }
+
+    /// Held out in front, so lobbed shots and fumes get around it
+    pub fn is_shield(&self) -> bool {
+        matches!(self, AccessoryKind::ScreenDoor)
+    }
+
+    /// Draws the accessory on its own, centred on (x, y)
+    pub fn draw_at(&self, x: f32, y: f32) {
+        match self {
+            AccessoryKind::Cone => draw_triangle(
+                vec2(x, y - 10.0),
+                vec2(x - 20.0, y + 10.0),
+                vec2(x + 20.0, y + 10.0),
+                ORANGE,
+            ),
+            AccessoryKind::Bucket => draw_rectangle(x - 20.0, y - 15.0, 40.0, 30.0, GRAY),
+            AccessoryKind::ScreenDoor => {
+                draw_rectangle(x - 18.0, y - 30.0, 36.0, 60.0, LIGHTGRAY);
+                draw_rectangle_lines(x - 18.0, y - 30.0, 36.0, 60.0, 3.0, DARKGRAY);
+            }
+            AccessoryKind::Balloon => {
+                draw_line(x, y, x, y + 30.0, 1.5, DARKGRAY);
+                draw_ellipse(x, y - 8.0, 14.0, 18.0, 0.0, RED);
+                draw_circle(x - 5.0, y - 14.0, 3.0, PINK);
+            }
+        }
+    }
+}
+
+pub struct Accessory {
+    pub kind: AccessoryKind,
+    pub health: i32,
+}
+
+impl Accessory {
+    pub fn new(kind: AccessoryKind, health: i32) -> Self {
+        Self { kind, health }
+    }
+}
+
+/// Runs a hit through the accessory in `slot`, dropping it once broken.
+/// Returns the damage that gets through to the zombie.
+pub fn absorb(slot: &mut Option<Accessory>, amount: i32) -> i32 {
+    let Some(accessory) = slot else {
+        return amount;
+    };
+    accessory.health -= amount;
+    if accessory.health > 0 {
+        return 0;
+    }
+    let overflow = -accessory.health;
+    *slot = None;
+    overflow
+}
+
+/// Like `absorb`, but a shield held out in front lets lobbed and piercing hits past it
+pub fn absorb_unless_shield(slot: &mut Option<Accessory>, amount: i32) -> i32 {
+    match slot {
+        Some(accessory) if accessory.kind.is_shield() => amount,
+        _ => absorb(slot, amount),
+    }
+}
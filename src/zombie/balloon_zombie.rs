@@ -0,0 +1,134 @@
+use macroquad::prelude::*;
+
+use crate::{
+    event::EventQueue,
+    factory::zombie_factory::ZombieType,
+    plant::plant::Plant,
+    projectile::projectile::Instakill,
+    zombie::{
+        accessory::{Accessory, AccessoryKind},
+        basic_zombie::BasicZombie,
+        zombie::{Altitude, Zombie, ZombieState},
+    },
+};
+
+// drifts a little faster than it walks
+const FLYING_SPEED: f32 = 30.0;
+// how far above its lane it floats
+const FLYING_HEIGHT: f32 = 30.0;
+
+/// Floats over every plant until its balloon is popped, then walks like a basic zombie
+pub struct BalloonZombie {
+    inner: BasicZombie,
+    balloon: Option<Accessory>,
+}
+
+impl BalloonZombie {
+    pub fn new(row: usize) -> Self {
+        Self {
+            inner: BasicZombie::new(row),
+            balloon: Some(Accessory::new(AccessoryKind::Balloon, 1)),
+        }
+    }
+}
+
+impl Zombie for BalloonZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::Balloon
+    }
+    fn x(&self) -> f32 {
+        self.inner.x()
+    }
+    fn y(&self) -> f32 {
+        self.inner.y()
+    }
+    fn row(&self) -> usize {
+        self.inner.row()
+    }
+    fn health(&self) -> i32 {
+        self.inner.health()
+    }
+    // anything that reaches it in the air pops the balloon and nothing more
+    fn take_damage(&mut self, amount: i32) {
+        if self.balloon.take().is_none() {
+            self.inner.take_damage(amount);
+        }
+    }
+    fn accessory(&self) -> Option<AccessoryKind> {
+        self.balloon.as_ref().map(|a| a.kind)
+    }
+    fn remove_accessory(&mut self) -> Option<AccessoryKind> {
+        self.balloon.take().map(|a| a.kind)
+    }
+    fn altitude(&self) -> Altitude {
+        if self.balloon.is_some() {
+            Altitude::Air
+        } else {
+            Altitude::Ground
+        }
+    }
+    fn blow_away(&mut self) {
+        if self.balloon.is_some() {
+            self.inner.swallow();
+        }
+    }
+    fn is_chewable(&self) -> bool {
+        self.balloon.is_none()
+    }
+    fn apply_slow(&mut self, duration: f32) {
+        self.inner.apply_slow(duration);
+    }
+    fn apply_stun(&mut self, duration: f32) {
+        self.inner.apply_stun(duration);
+    }
+    fn apply_freeze(&mut self, duration: f32) {
+        self.inner.apply_freeze(duration);
+    }
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
+        if self.balloon.is_none() {
+            self.inner.update(plants, events);
+            return;
+        }
+
+        // floating: no plant is in the way
+        if self.inner.is_dead() {
+            return;
+        }
+        if self.inner.health <= 0 {
+            self.inner.state = ZombieState::Dead;
+            return;
+        }
+        let dt = get_frame_time();
+        self.inner.status.update(dt);
+        if self.inner.status.is_stunned() {
+            return;
+        }
+        self.inner.state = ZombieState::Walking;
+        self.inner.x -= FLYING_SPEED * self.inner.status.speed_multiplier() * dt;
+    }
+    fn is_dead(&self) -> bool {
+        self.inner.is_dead()
+    }
+    fn is_instakill(&mut self, tier: Instakill) {
+        self.inner.is_instakill(tier);
+    }
+    fn swallow(&mut self) {
+        self.inner.swallow();
+    }
+
+    fn draw(&self) {
+        if self.balloon.is_none() {
+            self.inner.draw();
+            return;
+        }
+        if self.inner.is_dead() {
+            return;
+        }
+
+        // shadow on the lawn, body lifted above it
+        let (x, y) = (self.inner.x(), self.inner.y());
+        draw_ellipse(x, y + 45.0, 22.0, 8.0, 0.0, Color::new(0.0, 0.0, 0.0, 0.3));
+        draw_rectangle(x - 20.0, y - 40.0 - FLYING_HEIGHT, 40.0, 80.0, GREEN);
+        AccessoryKind::Balloon.draw_at(x, y - 80.0 - FLYING_HEIGHT);
+    }
+}
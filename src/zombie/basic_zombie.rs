@@ -1,47 +1,59 @@
 use macroquad::prelude::*;
 
 use crate::constants::*;
+use crate::event::EventQueue;
+use crate::factory::zombie_factory::ZombieType;
+use crate::grid::row_center_y;
 use crate::plant::plant::Plant;
 use crate::projectile::projectile::Instakill;
-use crate::zombie::zombie::{Zombie, ZombieState};
+use crate::zombie::status::StatusEffects;
+use crate::zombie::threat::{Threat, ThreatKind, strike};
+use crate::zombie::zombie::{Zombie, ZombieState, bite_target, divert_lane, step_toward_lane};
 
 pub struct BasicZombie {
     pub x: f32,
     pub y: f32,
+    pub row: usize,
     pub health: i32,
     pub speed: f32,
     pub state: ZombieState,
     pub attack_damage: i32,
     pub attack_cooldown: f32,
     pub last_attack_time: f64,
-    pub slow_timer: f32,
-    pub speed_multiplier: f32,
+    pub status: StatusEffects,
 }
 
 impl BasicZombie {
-    pub fn new(y: f32) -> Self {
+    pub fn new(row: usize) -> Self {
+        let y = row_center_y(row);
         Self {
             x: SCREEN_WIDTH,
             y,
+            row,
             health: 100,
             speed: 20.0,
             state: ZombieState::Walking,
             attack_damage: 20,
             attack_cooldown: 1.0,
             last_attack_time: 0.0,
-            slow_timer: 0.0,
-            speed_multiplier: 1.0,
+            status: StatusEffects::default(),
         }
     }
 }
 
 impl Zombie for BasicZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::Basic
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.health
     }
@@ -51,59 +63,71 @@ impl Zombie for BasicZombie {
     }
 
     fn apply_slow(&mut self, duration: f32) {
-        self.slow_timer = duration;
-        self.speed_multiplier = 0.5;
+        self.status.slow(duration);
+    }
+
+    fn apply_stun(&mut self, duration: f32) {
+        self.status.stun(duration);
+    }
+
+    fn apply_freeze(&mut self, duration: f32) {
+        self.status.freeze(duration);
     }
 
     fn is_dead(&self) -> bool {
-        match self.state {
-            ZombieState::Dead => true,
-            _ => false,
-        }
+        matches!(self.state, ZombieState::Dead)
     }
 
-    fn is_instakill(&mut self, tier: Instakill) {
+    fn is_instakill(&mut self, _tier: Instakill) {
         self.health = 0;
     }
 
-    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>) {
+    fn swallow(&mut self) {
+        self.health = 0;
+        self.state = ZombieState::Dead;
+    }
+
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
         if self.state == ZombieState::Dead {
             return;
         }
+        if self.health <= 0 {
+            self.state = ZombieState::Dead;
+            return;
+        }
 
         let dt = get_frame_time();
 
-        // handle slow
-        if self.slow_timer > 0.0 {
-            self.slow_timer -= dt;
-            if self.slow_timer <= 0.0 {
-                self.speed_multiplier = 1.0;
-            }
+        self.status.update(dt);
+        if self.status.is_stunned() {
+            return;
         }
 
-        // find a plant in front
-        let mut target: Option<&mut Box<dyn Plant>> = None;
-        for plant in plants.iter_mut() {
-            if (plant.y() - self.y).abs() < 40.0 && (plant.x() - self.x).abs() < 40.0 {
-                target = Some(plant);
-                break;
-            }
+        // still sidestepping into a new lane: keep walking, don't bite
+        if step_toward_lane(&mut self.y, self.row, dt) {
+            self.state = ZombieState::Walking;
+            self.x -= self.speed * self.status.speed_multiplier() * dt;
+            return;
         }
 
-        if let Some(plant) = target {
+        // find a plant in front
+        if let Some(target) = bite_target(plants, self.row, self.x) {
             self.state = ZombieState::Attacking;
             let now = get_time();
             if now - self.last_attack_time >= self.attack_cooldown as f64 {
                 self.last_attack_time = now;
-                plant.take_damage(self.attack_damage);
+                let bite = Threat {
+                    kind: ThreatKind::Bite,
+                    damage: self.attack_damage,
+                };
+                strike(plants, target, bite, events);
+                if !plants[target].is_dead() && plants[target].diverts_zombies() {
+                    self.row = divert_lane(self.row);
+                }
             }
         } else {
             self.state = ZombieState::Walking;
-            self.x -= self.speed * self.speed_multiplier * dt;
-        }
-
-        if self.health <= 0 {
-            self.state = ZombieState::Dead;
+            self.x -= self.speed * self.status.speed_multiplier() * dt;
         }
     }
 
@@ -112,7 +136,18 @@ impl Zombie for BasicZombie {
             return;
         }
 
-        let color = if self.slow_timer > 0.0 { BLUE } else { GREEN };
+        let color = if self.status.is_frozen() {
+            SKYBLUE
+        } else if self.status.is_slowed() {
+            BLUE
+        } else {
+            GREEN
+        };
         draw_rectangle(self.x - 20.0, self.y - 40.0, 40.0, 80.0, color);
+
+        // butter on the head while stunned
+        if self.status.is_buttered() {
+            draw_rectangle(self.x - 12.0, self.y - 46.0, 24.0, 12.0, YELLOW);
+        }
     }
 }
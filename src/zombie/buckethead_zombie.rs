@@ -1,41 +1,66 @@
-use macroquad::prelude::*;
-
 use crate::{
+    event::EventQueue,
+    factory::zombie_factory::ZombieType,
     plant::plant::Plant,
     projectile::projectile::Instakill,
-    zombie::{basic_zombie::BasicZombie, zombie::Zombie},
+    zombie::{
+        accessory::{Accessory, AccessoryKind, absorb},
+        basic_zombie::BasicZombie,
+        zombie::Zombie,
+    },
 };
 
 pub struct BucketheadZombie {
     inner: BasicZombie,
+    bucket: Option<Accessory>,
 }
 
 impl BucketheadZombie {
-    pub fn new(y: f32) -> Self {
-        let mut z = BasicZombie::new(y);
-        z.health = 350;
-        Self { inner: z }
+    pub fn new(row: usize) -> Self {
+        Self {
+            inner: BasicZombie::new(row),
+            bucket: Some(Accessory::new(AccessoryKind::Bucket, 250)),
+        }
     }
 }
 
 impl Zombie for BucketheadZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::Buckethead
+    }
     fn x(&self) -> f32 {
         self.inner.x()
     }
     fn y(&self) -> f32 {
         self.inner.y()
     }
+    fn row(&self) -> usize {
+        self.inner.row()
+    }
     fn health(&self) -> i32 {
-        self.inner.health()
+        self.inner.health() + self.bucket.as_ref().map_or(0, |a| a.health)
     }
     fn take_damage(&mut self, amount: i32) {
-        self.inner.take_damage(amount);
+        let rest = absorb(&mut self.bucket, amount);
+        self.inner.take_damage(rest);
+    }
+    fn accessory(&self) -> Option<AccessoryKind> {
+        self.bucket.as_ref().map(|a| a.kind)
+    }
+    fn remove_accessory(&mut self) -> Option<AccessoryKind> {
+        self.bucket.take().map(|a| a.kind)
     }
     fn apply_slow(&mut self, duration: f32) {
         self.inner.apply_slow(duration);
     }
-    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>) {
-        self.inner.update(plants);
+    fn apply_stun(&mut self, duration: f32) {
+        self.inner.apply_stun(duration);
+    }
+    fn apply_freeze(&mut self, duration: f32) {
+        self.inner.apply_freeze(duration);
+    }
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
+        self.inner.update(plants, events);
     }
     fn is_dead(&self) -> bool {
         self.inner.is_dead()
@@ -43,15 +68,14 @@ impl Zombie for BucketheadZombie {
     fn is_instakill(&mut self, tier: Instakill) {
         self.inner.is_instakill(tier);
     }
+    fn swallow(&mut self) {
+        self.inner.swallow();
+    }
 
     fn draw(&self) {
         self.inner.draw();
-
-        let x = self.inner.x();
-        let y = self.inner.y() - 60.0;
-        let bucket_width = 40.0;
-        let bucket_height = 30.0;
-
-        draw_rectangle(x - bucket_width / 2.0, y, bucket_width, bucket_height, GRAY);
+        if let Some(bucket) = &self.bucket {
+            bucket.kind.draw_at(self.inner.x(), self.inner.y() - 45.0);
+        }
     }
 }
@@ -0,0 +1,232 @@
+use macroquad::prelude::*;
+use macroquad::rand::ChooseRandom;
+
+use crate::constants::*;
+use crate::event::EventQueue;
+use crate::factory::zombie_factory::ZombieType;
+use crate::grid::row_center_y;
+use crate::plant::plant::{Plant, PlantLayer};
+use crate::projectile::projectile::Instakill;
+use crate::zombie::status::StatusEffects;
+use crate::zombie::threat::{Threat, ThreatKind, strike};
+use crate::zombie::zombie::{Altitude, Zombie, ZombieState, bite_target};
+
+// how high above the lawn the drop starts, and how fast it comes down and goes back up
+const DROP_HEIGHT: f32 = 400.0;
+const DROP_SPEED: f32 = 300.0;
+// how long it dangles on the lawn before snatching the plant
+const GRAB_TIME: f32 = 2.0;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BungeePhase {
+    Dropping,
+    Grabbing,
+    Rising,
+    /// Back off the top of the screen
+    Gone,
+}
+
+/// Drops down on a cord onto a plant anywhere on the lawn and hauls it away
+pub struct BungeeZombie {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    pub state: ZombieState,
+    pub status: StatusEffects,
+    pub phase: BungeePhase,
+    // how far above its tile it hangs
+    pub height: f32,
+    pub grab_timer: f32,
+    // false until it has picked a plant to drop on
+    pub aimed: bool,
+    pub carrying: bool,
+}
+
+impl BungeeZombie {
+    pub fn new(row: usize) -> Self {
+        Self {
+            x: SCREEN_WIDTH / 2.0,
+            y: row_center_y(row),
+            row,
+            health: 150,
+            state: ZombieState::Walking,
+            status: StatusEffects::default(),
+            phase: BungeePhase::Dropping,
+            height: DROP_HEIGHT,
+            grab_timer: GRAB_TIME,
+            aimed: false,
+            carrying: false,
+        }
+    }
+
+    /// Lane and x of a random plant it could carry off
+    fn pick_target(plants: &[Box<dyn Plant>]) -> Option<(usize, f32)> {
+        let candidates: Vec<(usize, f32)> = plants
+            .iter()
+            .filter(|p| !p.is_dead() && !matches!(p.layer(), PlantLayer::Ground | PlantLayer::Top))
+            .map(|p| (p.row(), p.x()))
+            .collect();
+        candidates.choose().copied()
+    }
+
+    fn snatch(&mut self, plants: &mut [Box<dyn Plant>], events: &mut EventQueue) {
+        self.phase = BungeePhase::Rising;
+        let Some(target) = bite_target(plants, self.row, self.x) else {
+            return;
+        };
+        let drop = Threat {
+            kind: ThreatKind::Drop,
+            damage: plants[target].health(),
+        };
+        strike(plants, target, drop, events);
+        // still standing means something above it took the hit
+        self.carrying = plants[target].is_dead();
+    }
+}
+
+impl Zombie for BungeeZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::Bungee
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn apply_slow(&mut self, duration: f32) {
+        self.status.slow(duration);
+    }
+
+    fn apply_stun(&mut self, duration: f32) {
+        self.status.stun(duration);
+    }
+
+    fn apply_freeze(&mut self, duration: f32) {
+        self.status.freeze(duration);
+    }
+
+    fn is_dead(&self) -> bool {
+        matches!(self.state, ZombieState::Dead)
+    }
+
+    fn is_gone(&self) -> bool {
+        self.phase == BungeePhase::Gone
+    }
+
+    fn is_instakill(&mut self, _tier: Instakill) {
+        self.health = 0;
+    }
+
+    fn swallow(&mut self) {
+        self.health = 0;
+        self.state = ZombieState::Dead;
+    }
+
+    // only in reach while it dangles on the lawn
+    fn is_chewable(&self) -> bool {
+        self.phase == BungeePhase::Grabbing
+    }
+
+    fn altitude(&self) -> Altitude {
+        if self.phase == BungeePhase::Grabbing {
+            Altitude::Ground
+        } else {
+            Altitude::Air
+        }
+    }
+
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
+        if self.state == ZombieState::Dead || self.phase == BungeePhase::Gone {
+            return;
+        }
+        if self.health <= 0 {
+            self.state = ZombieState::Dead;
+            return;
+        }
+
+        let dt = get_frame_time();
+
+        if !self.aimed {
+            self.aimed = true;
+            match Self::pick_target(plants) {
+                Some((row, x)) => {
+                    self.row = row;
+                    self.x = x;
+                    self.y = row_center_y(row);
+                }
+                // nothing to take: go straight back up
+                None => self.phase = BungeePhase::Rising,
+            }
+        }
+
+        self.status.update(dt);
+        if self.status.is_stunned() {
+            return;
+        }
+
+        match self.phase {
+            BungeePhase::Dropping => {
+                self.height -= DROP_SPEED * dt;
+                if self.height <= 0.0 {
+                    self.height = 0.0;
+                    self.phase = BungeePhase::Grabbing;
+                }
+            }
+            BungeePhase::Grabbing => {
+                self.state = ZombieState::Attacking;
+                self.grab_timer -= dt * self.status.speed_multiplier();
+                if self.grab_timer <= 0.0 {
+                    self.snatch(plants, events);
+                }
+            }
+            BungeePhase::Rising => {
+                self.state = ZombieState::Walking;
+                self.height += DROP_SPEED * dt;
+                // gone off the top of the screen: it has left the lawn
+                if self.height >= DROP_HEIGHT {
+                    self.phase = BungeePhase::Gone;
+                }
+            }
+            BungeePhase::Gone => {}
+        }
+    }
+
+    fn draw(&self) {
+        if self.state == ZombieState::Dead || self.phase == BungeePhase::Gone || !self.aimed {
+            return;
+        }
+
+        let color = if self.status.is_frozen() {
+            SKYBLUE
+        } else if self.status.is_slowed() {
+            BLUE
+        } else {
+            GREEN
+        };
+
+        let y = self.y - self.height;
+        // the cord it hangs from
+        draw_line(self.x, 0.0, self.x, y - 40.0, 2.0, DARKGRAY);
+        draw_rectangle(self.x - 15.0, y - 40.0, 30.0, 45.0, color);
+        if self.carrying {
+            draw_circle(self.x, y + 14.0, 12.0, DARKGREEN);
+        }
+
+        if self.status.is_buttered() {
+            draw_rectangle(self.x - 12.0, y - 46.0, 24.0, 12.0, YELLOW);
+        }
+    }
+}
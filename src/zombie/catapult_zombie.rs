@@ -0,0 +1,255 @@
+use macroquad::prelude::*;
+
+use crate::constants::*;
+use crate::event::EventQueue;
+use crate::factory::zombie_factory::ZombieType;
+use crate::grid::row_center_y;
+use crate::plant::plant::{Plant, PlantLayer};
+use crate::projectile::projectile::Instakill;
+use crate::zombie::status::StatusEffects;
+use crate::zombie::threat::{Threat, ThreatKind, strike};
+use crate::zombie::zombie::{Zombie, ZombieState, bite_target, divert_lane, step_toward_lane};
+
+const THROW_COOLDOWN: f32 = 3.0;
+const BALL_FLIGHT_TIME: f32 = 1.0;
+const BALL_DAMAGE: i32 = 40;
+// where it stops to throw, the middle of the second column from the right
+const PARK_X: f32 = TILE_SIZE * (COLS as f32 - 1.5);
+
+/// A basketball on its way to the lawn
+pub struct Basketball {
+    pub start_x: f32,
+    pub target_x: f32,
+    pub elapsed: f32,
+}
+
+/// Rolls onto the lawn and parks in the second column from the right, or sooner if a
+/// plant is in the way, then lobs basketballs at the plant furthest back in its lane
+pub struct CatapultZombie {
+    pub x: f32,
+    pub y: f32,
+    pub row: usize,
+    pub health: i32,
+    pub speed: f32,
+    pub state: ZombieState,
+    pub attack_damage: i32,
+    pub attack_cooldown: f32,
+    pub last_attack_time: f64,
+    pub status: StatusEffects,
+    pub basketballs: u32,
+    pub throw_timer: f32,
+    pub ball: Option<Basketball>,
+}
+
+impl CatapultZombie {
+    pub fn new(row: usize) -> Self {
+        Self {
+            x: SCREEN_WIDTH,
+            y: row_center_y(row),
+            row,
+            health: 200,
+            speed: 15.0,
+            state: ZombieState::Walking,
+            attack_damage: 20,
+            attack_cooldown: 1.0,
+            last_attack_time: 0.0,
+            status: StatusEffects::default(),
+            basketballs: 20,
+            throw_timer: 0.0,
+            ball: None,
+        }
+    }
+
+    /// x of the rearmost plant in the lane ahead, if any
+    fn lob_target(&self, plants: &[Box<dyn Plant>]) -> Option<f32> {
+        plants
+            .iter()
+            .filter(|p| {
+                p.row() == self.row
+                    && p.x() < self.x
+                    && !p.is_dead()
+                    && !matches!(p.layer(), PlantLayer::Ground | PlantLayer::Top)
+            })
+            .map(|p| p.x())
+            .min_by(f32::total_cmp)
+    }
+
+    fn land_ball(&mut self, plants: &mut [Box<dyn Plant>], events: &mut EventQueue) {
+        let Some(ball) = self.ball.take() else {
+            return;
+        };
+        if let Some(target) = bite_target(plants, self.row, ball.target_x) {
+            let hit = Threat {
+                kind: ThreatKind::Lobbed,
+                damage: BALL_DAMAGE,
+            };
+            strike(plants, target, hit, events);
+        }
+    }
+}
+
+impl Zombie for CatapultZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::Catapult
+    }
+    fn x(&self) -> f32 {
+        self.x
+    }
+    fn y(&self) -> f32 {
+        self.y
+    }
+    fn row(&self) -> usize {
+        self.row
+    }
+    fn health(&self) -> i32 {
+        self.health
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+
+    fn apply_slow(&mut self, duration: f32) {
+        self.status.slow(duration);
+    }
+
+    fn apply_stun(&mut self, duration: f32) {
+        self.status.stun(duration);
+    }
+
+    fn apply_freeze(&mut self, duration: f32) {
+        self.status.freeze(duration);
+    }
+
+    fn is_dead(&self) -> bool {
+        matches!(self.state, ZombieState::Dead)
+    }
+
+    fn is_instakill(&mut self, _tier: Instakill) {
+        self.health = 0;
+    }
+
+    fn swallow(&mut self) {
+        self.health = 0;
+        self.state = ZombieState::Dead;
+    }
+
+    fn is_wheeled(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
+        if self.state == ZombieState::Dead {
+            return;
+        }
+        if self.health <= 0 {
+            self.state = ZombieState::Dead;
+            return;
+        }
+
+        let dt = get_frame_time();
+
+        // a ball already thrown still comes down
+        if let Some(ball) = &mut self.ball {
+            ball.elapsed += dt;
+            if ball.elapsed >= BALL_FLIGHT_TIME {
+                self.land_ball(plants, events);
+            }
+        }
+
+        self.status.update(dt);
+        if self.status.is_stunned() {
+            return;
+        }
+
+        // still sidestepping into a new lane: keep rolling, don't attack
+        if step_toward_lane(&mut self.y, self.row, dt) {
+            self.state = ZombieState::Walking;
+            self.x -= self.speed * self.status.speed_multiplier() * dt;
+            return;
+        }
+
+        // park and throw while there is ammo and something to throw at
+        let parked = self.x <= PARK_X || bite_target(plants, self.row, self.x).is_some();
+        if self.basketballs > 0
+            && parked
+            && let Some(target_x) = self.lob_target(plants)
+        {
+            self.state = ZombieState::Attacking;
+            self.throw_timer -= dt;
+            if self.throw_timer <= 0.0 && self.ball.is_none() {
+                self.throw_timer = THROW_COOLDOWN;
+                self.basketballs -= 1;
+                self.ball = Some(Basketball {
+                    start_x: self.x,
+                    target_x,
+                    elapsed: 0.0,
+                });
+            }
+            return;
+        }
+
+        // out of balls: run plants over like anyone else
+        if let Some(target) = bite_target(plants, self.row, self.x) {
+            self.state = ZombieState::Attacking;
+            let now = get_time();
+            if now - self.last_attack_time >= self.attack_cooldown as f64 {
+                self.last_attack_time = now;
+                let bite = Threat {
+                    kind: ThreatKind::Bite,
+                    damage: self.attack_damage,
+                };
+                strike(plants, target, bite, events);
+                if !plants[target].is_dead() && plants[target].diverts_zombies() {
+                    self.row = divert_lane(self.row);
+                }
+            }
+        } else {
+            self.state = ZombieState::Walking;
+            self.x -= self.speed * self.status.speed_multiplier() * dt;
+        }
+    }
+
+    fn draw(&self) {
+        if self.state == ZombieState::Dead {
+            return;
+        }
+
+        let color = if self.status.is_frozen() {
+            SKYBLUE
+        } else if self.status.is_slowed() {
+            BLUE
+        } else {
+            GREEN
+        };
+
+        // cart with the zombie riding on top
+        draw_rectangle(self.x - 30.0, self.y, 60.0, 30.0, BROWN);
+        draw_circle(self.x - 18.0, self.y + 32.0, 8.0, DARKGRAY);
+        draw_circle(self.x + 18.0, self.y + 32.0, 8.0, DARKGRAY);
+        draw_rectangle(self.x - 15.0, self.y - 40.0, 30.0, 45.0, color);
+        // throwing arm
+        draw_line(
+            self.x + 10.0,
+            self.y,
+            self.x + 30.0,
+            self.y - 30.0,
+            4.0,
+            DARKBROWN,
+        );
+        if self.basketballs > 0 && self.ball.is_none() {
+            draw_circle(self.x + 30.0, self.y - 36.0, 7.0, ORANGE);
+        }
+
+        if let Some(ball) = &self.ball {
+            let t = (ball.elapsed / BALL_FLIGHT_TIME).min(1.0);
+            let height = 120.0 * 4.0 * t * (1.0 - t);
+            let x = ball.start_x + (ball.target_x - ball.start_x) * t;
+            draw_circle(x, self.y - height, 7.0, ORANGE);
+        }
+
+        if self.status.is_buttered() {
+            draw_rectangle(self.x - 12.0, self.y - 46.0, 24.0, 12.0, YELLOW);
+        }
+    }
+}
@@ -1,41 +1,66 @@
-use macroquad::prelude::*;
-
 use crate::{
+    event::EventQueue,
+    factory::zombie_factory::ZombieType,
     plant::plant::Plant,
     projectile::projectile::Instakill,
-    zombie::{basic_zombie::BasicZombie, zombie::Zombie},
+    zombie::{
+        accessory::{Accessory, AccessoryKind, absorb},
+        basic_zombie::BasicZombie,
+        zombie::Zombie,
+    },
 };
 
 pub struct ConeheadZombie {
     inner: BasicZombie,
+    cone: Option<Accessory>,
 }
 
 impl ConeheadZombie {
-    pub fn new(y: f32) -> Self {
-        let mut z = BasicZombie::new(y);
-        z.health = 200;
-        Self { inner: z }
+    pub fn new(row: usize) -> Self {
+        Self {
+            inner: BasicZombie::new(row),
+            cone: Some(Accessory::new(AccessoryKind::Cone, 100)),
+        }
     }
 }
 
 impl Zombie for ConeheadZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::Conehead
+    }
     fn x(&self) -> f32 {
         self.inner.x()
     }
     fn y(&self) -> f32 {
         self.inner.y()
     }
+    fn row(&self) -> usize {
+        self.inner.row()
+    }
     fn health(&self) -> i32 {
-        self.inner.health()
+        self.inner.health() + self.cone.as_ref().map_or(0, |a| a.health)
     }
     fn take_damage(&mut self, amount: i32) {
-        self.inner.take_damage(amount);
+        let rest = absorb(&mut self.cone, amount);
+        self.inner.take_damage(rest);
+    }
+    fn accessory(&self) -> Option<AccessoryKind> {
+        self.cone.as_ref().map(|a| a.kind)
+    }
+    fn remove_accessory(&mut self) -> Option<AccessoryKind> {
+        self.cone.take().map(|a| a.kind)
     }
     fn apply_slow(&mut self, duration: f32) {
         self.inner.apply_slow(duration);
     }
-    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>) {
-        self.inner.update(plants);
+    fn apply_stun(&mut self, duration: f32) {
+        self.inner.apply_stun(duration);
+    }
+    fn apply_freeze(&mut self, duration: f32) {
+        self.inner.apply_freeze(duration);
+    }
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
+        self.inner.update(plants, events);
     }
     fn is_dead(&self) -> bool {
         self.inner.is_dead()
@@ -43,15 +68,15 @@ impl Zombie for ConeheadZombie {
     fn is_instakill(&mut self, tier: Instakill) {
         self.inner.is_instakill(tier);
     }
+    fn swallow(&mut self) {
+        self.inner.swallow();
+    }
 
     fn draw(&self) {
         self.inner.draw();
         // draw cone hat
-        draw_triangle(
-            vec2(self.inner.x(), self.inner.y() - 60.0),
-            vec2(self.inner.x() - 20.0, self.inner.y() - 40.0),
-            vec2(self.inner.x() + 20.0, self.inner.y() - 40.0),
-            ORANGE,
-        );
+        if let Some(cone) = &self.cone {
+            cone.kind.draw_at(self.inner.x(), self.inner.y() - 50.0);
+        }
     }
 }
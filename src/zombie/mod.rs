@@ -1,5 +1,13 @@
+pub mod accessory;
+pub mod balloon_zombie;
 pub mod basic_zombie;
 pub mod buckethead_zombie;
+pub mod bungee_zombie;
+pub mod catapult_zombie;
 pub mod conehead_zombie;
 pub mod pole_zombie;
+pub mod screen_door_zombie;
+pub mod status;
+pub mod threat;
+#[allow(clippy::module_inception)]
 pub mod zombie;
@@ -1,24 +1,29 @@
-use std::clone;
-
 use macroquad::prelude::*;
 
 use crate::constants::*;
+use crate::event::EventQueue;
+use crate::factory::zombie_factory::ZombieType;
+use crate::grid::row_center_y;
 use crate::plant::plant::Plant;
 use crate::projectile::projectile::Instakill;
-use crate::zombie::zombie::{Zombie, ZombieState};
+use crate::zombie::status::StatusEffects;
+use crate::zombie::threat::{Threat, ThreatKind, strike};
+use crate::zombie::zombie::{
+    BITE_REACH, Zombie, ZombieState, bite_target, divert_lane, step_toward_lane,
+};
 
 pub struct PoleZombie {
     pub x: f32,
     pub y: f32,
-    pub base_y: f32, // fixed lane row
+    pub row: usize,
+    pub base_y: f32, // ground level, follows the lane when diverted
     pub health: i32,
     pub speed: f32,
     pub state: ZombieState,
     pub attack_damage: i32,
     pub attack_cooldown: f32,
     pub last_attack_time: f64,
-    pub slow_timer: f32,
-    pub speed_multiplier: f32,
+    pub status: StatusEffects,
     pub has_pole: bool,
     pub jump_start_x: f32,
     pub jump_target_x: f32,
@@ -27,10 +32,12 @@ pub struct PoleZombie {
 }
 
 impl PoleZombie {
-    pub fn new(y: f32) -> Self {
+    pub fn new(row: usize) -> Self {
+        let y = row_center_y(row);
         Self {
             x: SCREEN_WIDTH,
             y,
+            row,
             base_y: y,
             health: 100,
             speed: 30.0,
@@ -38,8 +45,7 @@ impl PoleZombie {
             attack_damage: 20,
             attack_cooldown: 1.0,
             last_attack_time: 0.0,
-            slow_timer: 0.0,
-            speed_multiplier: 1.0,
+            status: StatusEffects::default(),
             has_pole: true,
             jump_start_x: 0.0,
             jump_target_x: 0.0,
@@ -50,12 +56,18 @@ impl PoleZombie {
 }
 
 impl Zombie for PoleZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::PoleZombie
+    }
     fn x(&self) -> f32 {
         self.x
     }
     fn y(&self) -> f32 {
         self.y
     }
+    fn row(&self) -> usize {
+        self.row
+    }
     fn health(&self) -> i32 {
         self.health
     }
@@ -65,8 +77,15 @@ impl Zombie for PoleZombie {
     }
 
     fn apply_slow(&mut self, duration: f32) {
-        self.slow_timer = duration;
-        self.speed_multiplier = 0.5;
+        self.status.slow(duration);
+    }
+
+    fn apply_stun(&mut self, duration: f32) {
+        self.status.stun(duration);
+    }
+
+    fn apply_freeze(&mut self, duration: f32) {
+        self.status.freeze(duration);
     }
 
     fn is_dead(&self) -> bool {
@@ -77,19 +96,31 @@ impl Zombie for PoleZombie {
         self.health = 0;
     }
 
-    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>) {
+    fn swallow(&mut self) {
+        self.health = 0;
+        self.state = ZombieState::Dead;
+    }
+
+    // can't be caught mid-vault
+    fn is_chewable(&self) -> bool {
+        self.state != ZombieState::Jumping
+    }
+
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
         if self.state == ZombieState::Dead {
             return;
         }
+        if self.health <= 0 {
+            self.state = ZombieState::Dead;
+            return;
+        }
 
         let dt = get_frame_time();
 
-        // handle slow
-        if self.slow_timer > 0.0 {
-            self.slow_timer -= dt;
-            if self.slow_timer <= 0.0 {
-                self.speed_multiplier = 1.0;
-            }
+        self.status.update(dt);
+        // a vault already in the air still lands
+        if self.status.is_stunned() && self.state != ZombieState::Jumping {
+            return;
         }
 
         match self.state {
@@ -110,24 +141,30 @@ impl Zombie for PoleZombie {
             }
             _ => {
                 // always reset to base lane when not jumping
+                let switching = step_toward_lane(&mut self.base_y, self.row, dt);
                 self.y = self.base_y;
-
-                // check for plant collision
-                let mut target: Option<&mut Box<dyn Plant>> = None;
-                for plant in plants.iter_mut() {
-                    if (plant.y() - self.y).abs() < 40.0 && (plant.x() - self.x).abs() < 40.0 {
-                        target = Some(plant);
-                        break;
-                    }
+                if switching {
+                    self.state = ZombieState::Walking;
+                    self.x -= self.speed * self.status.speed_multiplier() * dt;
+                    self.pole_x = self.x + 15.0;
+                    return;
                 }
 
-                if let Some(plant) = target {
+                // check for plant collision; a tall plant anywhere on the tile blocks the vault
+                let blocked = plants.iter().any(|p| {
+                    p.row() == self.row
+                        && (p.x() - self.x).abs() < BITE_REACH
+                        && !p.is_dead()
+                        && p.is_tall()
+                });
+
+                if let Some(target) = bite_target(plants, self.row, self.x) {
                     if self.has_pole {
                         // initiate jump
                         self.state = ZombieState::Jumping;
                         self.jump_start_x = self.x;
 
-                        if plant.is_tall() {
+                        if blocked {
                             self.jump_target_x = self.x;
                         } else {
                             self.jump_target_x = self.x - 80.0;
@@ -141,21 +178,24 @@ impl Zombie for PoleZombie {
                         let now = get_time();
                         if now - self.last_attack_time >= self.attack_cooldown as f64 {
                             self.last_attack_time = now;
-                            plant.take_damage(self.attack_damage);
+                            let bite = Threat {
+                                kind: ThreatKind::Bite,
+                                damage: self.attack_damage,
+                            };
+                            strike(plants, target, bite, events);
+                            if !plants[target].is_dead() && plants[target].diverts_zombies() {
+                                self.row = divert_lane(self.row);
+                            }
                         }
                     }
                 } else {
                     // walk
                     self.state = ZombieState::Walking;
-                    self.x -= self.speed * self.speed_multiplier * dt;
+                    self.x -= self.speed * self.status.speed_multiplier() * dt;
                     self.pole_x = self.x + 15.0;
                 }
             }
         }
-
-        if self.health <= 0 {
-            self.state = ZombieState::Dead;
-        }
     }
 
     fn draw(&self) {
@@ -163,10 +203,19 @@ impl Zombie for PoleZombie {
             return;
         }
 
-        let color = if self.slow_timer > 0.0 { BLUE } else { GREEN };
+        let color = if self.status.is_frozen() {
+            SKYBLUE
+        } else if self.status.is_slowed() {
+            BLUE
+        } else {
+            GREEN
+        };
 
         // zombie body
         draw_rectangle(self.x - 20.0, self.y - 40.0, 40.0, 80.0, color);
+        if self.status.is_buttered() {
+            draw_rectangle(self.x - 12.0, self.y - 46.0, 24.0, 12.0, YELLOW);
+        }
 
         // pole sticks in the ground (not jumping with zombie)
         if self.has_pole {
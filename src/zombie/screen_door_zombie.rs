@@ -0,0 +1,91 @@
+use crate::{
+    event::EventQueue,
+    factory::zombie_factory::ZombieType,
+    plant::plant::Plant,
+    projectile::projectile::Instakill,
+    zombie::{
+        accessory::{Accessory, AccessoryKind, absorb, absorb_unless_shield},
+        basic_zombie::BasicZombie,
+        zombie::Zombie,
+    },
+};
+
+pub struct ScreenDoorZombie {
+    inner: BasicZombie,
+    door: Option<Accessory>,
+}
+
+impl ScreenDoorZombie {
+    pub fn new(row: usize) -> Self {
+        Self {
+            inner: BasicZombie::new(row),
+            door: Some(Accessory::new(AccessoryKind::ScreenDoor, 250)),
+        }
+    }
+}
+
+impl Zombie for ScreenDoorZombie {
+    fn zombie_type(&self) -> ZombieType {
+        ZombieType::ScreenDoor
+    }
+    fn x(&self) -> f32 {
+        self.inner.x()
+    }
+    fn y(&self) -> f32 {
+        self.inner.y()
+    }
+    fn row(&self) -> usize {
+        self.inner.row()
+    }
+    fn health(&self) -> i32 {
+        self.inner.health() + self.door.as_ref().map_or(0, |a| a.health)
+    }
+    fn take_damage(&mut self, amount: i32) {
+        let rest = absorb(&mut self.door, amount);
+        self.inner.take_damage(rest);
+    }
+    // the door only covers the front
+    fn take_lobbed_damage(&mut self, amount: i32) {
+        let rest = absorb_unless_shield(&mut self.door, amount);
+        self.inner.take_damage(rest);
+    }
+    fn take_piercing_damage(&mut self, amount: i32) {
+        let rest = absorb_unless_shield(&mut self.door, amount);
+        self.inner.take_damage(rest);
+    }
+    fn accessory(&self) -> Option<AccessoryKind> {
+        self.door.as_ref().map(|a| a.kind)
+    }
+    fn remove_accessory(&mut self) -> Option<AccessoryKind> {
+        self.door.take().map(|a| a.kind)
+    }
+    fn apply_slow(&mut self, duration: f32) {
+        self.inner.apply_slow(duration);
+    }
+    fn apply_stun(&mut self, duration: f32) {
+        self.inner.apply_stun(duration);
+    }
+    fn apply_freeze(&mut self, duration: f32) {
+        self.inner.apply_freeze(duration);
+    }
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue) {
+        self.inner.update(plants, events);
+    }
+    fn is_dead(&self) -> bool {
+        self.inner.is_dead()
+    }
+    fn is_instakill(&mut self, tier: Instakill) {
+        self.inner.is_instakill(tier);
+    }
+    fn swallow(&mut self) {
+        self.inner.swallow();
+    }
+
+    fn draw(&self) {
+        self.inner.draw();
+        if let Some(door) = &self.door {
+            // held out in front of the body
+            door.kind.draw_at(self.inner.x() - 18.0, self.inner.y());
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/// Timed effects plants can put on any zombie
+#[derive(Default)]
+pub struct StatusEffects {
+    pub slow_timer: f32,
+    pub stun_timer: f32,
+    pub freeze_timer: f32,
+}
+
+impl StatusEffects {
+    pub fn update(&mut self, dt: f32) {
+        self.slow_timer = (self.slow_timer - dt).max(0.0);
+        self.stun_timer = (self.stun_timer - dt).max(0.0);
+        self.freeze_timer = (self.freeze_timer - dt).max(0.0);
+    }
+
+    pub fn slow(&mut self, duration: f32) {
+        self.slow_timer = self.slow_timer.max(duration);
+    }
+
+    pub fn stun(&mut self, duration: f32) {
+        self.stun_timer = self.stun_timer.max(duration);
+    }
+
+    pub fn freeze(&mut self, duration: f32) {
+        self.freeze_timer = self.freeze_timer.max(duration);
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.freeze_timer > 0.0
+    }
+
+    pub fn is_buttered(&self) -> bool {
+        self.stun_timer > 0.0
+    }
+
+    pub fn is_slowed(&self) -> bool {
+        self.slow_timer > 0.0
+    }
+
+    /// Stunned or frozen zombies neither walk nor bite
+    pub fn is_stunned(&self) -> bool {
+        self.is_buttered() || self.is_frozen()
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.is_slowed() { 0.5 } else { 1.0 }
+    }
+}
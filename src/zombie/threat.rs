@@ -0,0 +1,47 @@
+use crate::{
+    event::{EventQueue, GameEvent},
+    plant::plant::Plant,
+};
+
+/// How a zombie attack reaches the plant it is aimed at
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThreatKind {
+    Bite,
+    /// Thrown in an arc, so it comes down on the plant from above
+    Lobbed,
+    /// Lowered onto the plant on a cord to carry it off
+    Drop,
+}
+
+/// A zombie attack on its way to a plant, which other plants may stop before it lands
+#[derive(Copy, Clone, Debug)]
+pub struct Threat {
+    pub kind: ThreatKind,
+    pub damage: i32,
+}
+
+/// Lands `threat` on `plants[target]` unless a plant guarding it intercepts it first
+pub fn strike(
+    plants: &mut [Box<dyn Plant>],
+    target: usize,
+    threat: Threat,
+    events: &mut EventQueue,
+) {
+    let (x, y) = (plants[target].x(), plants[target].y());
+    if plants
+        .iter_mut()
+        .any(|p| !p.is_dead() && p.intercept(&threat, x, y))
+    {
+        return;
+    }
+
+    let plant = &mut plants[target];
+    plant.take_damage(threat.damage);
+    if plant.is_dead() {
+        events.push(GameEvent::PlantEaten {
+            plant_type: plant.plant_type(),
+            x: plant.x(),
+            y: plant.y(),
+        });
+    }
+}
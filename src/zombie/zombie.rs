@@ -1,4 +1,25 @@
-use crate::{plant::plant::Plant, projectile::projectile::Instakill};
+use macroquad::rand::ChooseRandom;
+
+use crate::{
+    event::EventQueue,
+    factory::zombie_factory::ZombieType,
+    grid::{lanes_around, row_center_y},
+    plant::plant::{Plant, PlantLayer},
+    projectile::projectile::Instakill,
+    zombie::accessory::AccessoryKind,
+};
+
+// how close a zombie has to be to a plant to bite it
+pub const BITE_REACH: f32 = 40.0;
+// how fast a diverted zombie slides sideways into its new lane
+pub const LANE_CHANGE_SPEED: f32 = 60.0;
+
+/// How high off the lawn a zombie is; most attacks only reach the ground
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Altitude {
+    Ground,
+    Air,
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ZombieState {
@@ -9,15 +30,96 @@ pub enum ZombieState {
 }
 
 pub trait Zombie {
+    fn zombie_type(&self) -> ZombieType;
     fn x(&self) -> f32;
     fn y(&self) -> f32;
+    /// Lane the zombie is walking down
+    fn row(&self) -> usize;
     fn health(&self) -> i32;
     fn is_dead(&self) -> bool;
+    /// Left the lawn on its own; removed without counting as a kill
+    fn is_gone(&self) -> bool {
+        false
+    }
 
     fn take_damage(&mut self, amount: i32);
     fn apply_slow(&mut self, duration: f32);
+    fn apply_stun(&mut self, duration: f32);
+    /// Frozen solid: stopped like a stun, but drawn iced over
+    fn apply_freeze(&mut self, duration: f32);
+    /// Hit from above by a lobbed projectile, which flies over anything held in front
+    fn take_lobbed_damage(&mut self, amount: i32) {
+        self.take_damage(amount);
+    }
+    /// Fumes and spikes, which get past anything held in front
+    fn take_piercing_damage(&mut self, amount: i32) {
+        self.take_damage(amount);
+    }
     fn is_instakill(&mut self, tier: Instakill);
+    fn accessory(&self) -> Option<AccessoryKind> {
+        None
+    }
+    /// Takes the zombie's accessory away from it, if it has one
+    fn remove_accessory(&mut self) -> Option<AccessoryKind> {
+        None
+    }
+    /// Eaten whole by a plant; dies regardless of health or armor
+    fn swallow(&mut self);
+    /// Whether a plant can swallow it (too big or out of reach otherwise)
+    fn is_chewable(&self) -> bool {
+        true
+    }
+    /// Rides a vehicle that ground spikes can pop
+    fn is_wheeled(&self) -> bool {
+        false
+    }
+    fn altitude(&self) -> Altitude {
+        Altitude::Ground
+    }
+    fn is_grounded(&self) -> bool {
+        self.altitude() == Altitude::Ground
+    }
+    /// Caught in a gust of wind; only flying zombies are light enough to be carried off
+    fn blow_away(&mut self) {}
 
-    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>);
+    fn update(&mut self, plants: &mut Vec<Box<dyn Plant>>, events: &mut EventQueue);
     fn draw(&self);
 }
+
+/// Index of the plant a zombie at `x` in `row` would bite, shells before what they protect;
+/// ground plants are walked over and plants on top are out of reach
+pub fn bite_target(plants: &[Box<dyn Plant>], row: usize, x: f32) -> Option<usize> {
+    plants
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| {
+            p.row() == row
+                && (p.x() - x).abs() < BITE_REACH
+                && !p.is_dead()
+                && !matches!(p.layer(), PlantLayer::Ground | PlantLayer::Top)
+        })
+        .min_by_key(|(_, p)| p.layer() != PlantLayer::Shell)
+        .map(|(i, _)| i)
+}
+
+/// A random neighbouring lane for a zombie turned away from `row`
+pub fn divert_lane(row: usize) -> usize {
+    let lanes: Vec<usize> = lanes_around(row)
+        .into_iter()
+        .filter(|&r| r != row)
+        .collect();
+    lanes.choose().copied().unwrap_or(row)
+}
+
+/// Slides `y` toward the middle of `row`; true while the zombie is still between lanes
+pub fn step_toward_lane(y: &mut f32, row: usize, dt: f32) -> bool {
+    let target = row_center_y(row);
+    let step = LANE_CHANGE_SPEED * dt;
+    if (target - *y).abs() <= step {
+        *y = target;
+        false
+    } else {
+        *y += step * (target - *y).signum();
+        true
+    }
+}